@@ -0,0 +1,207 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::my_io::read_input_to_vector;
+use crate::registry::YEARS;
+
+pub const DATA_DIRECTORY: &str = "data";
+const EXAMPLES_DIRECTORY: &str = "examples";
+const ANSWERS_FILE: &str = "answers.txt";
+
+/// Marks an answer that is not known (yet), e.g. the examples of day 8 only have an answer for one part
+const UNKNOWN_ANSWER: &str = "-";
+
+/// The expected answers for one input file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_1: Option<String>,
+    pub part_2: Option<String>,
+}
+
+/// Every year has its own answers store `data/<year>/answers.txt` with one line per input with the format
+/// <input name> <answer part 1> <answer part 2>
+/// where the input name is the file name without extension, e.g. `day5` or `day5-1` for the first example.
+/// Lines starting with # are comments.
+pub fn parse_answers(lines: &Vec<String>) -> BTreeMap<String, Answers> {
+    let mut answers = BTreeMap::new();
+    for line in lines {
+        if line.trim().is_empty() || line.starts_with("#") {
+            continue;
+        }
+        let mut line_split = line.split_whitespace();
+        let name = line_split.next().unwrap().to_string();
+        let mut next_answer = || {
+            line_split
+                .next()
+                .filter(|a| *a != UNKNOWN_ANSWER)
+                .map(|a| a.to_string())
+        };
+        let part_1 = next_answer();
+        let part_2 = next_answer();
+        answers.insert(name, Answers { part_1, part_2 });
+    }
+    answers
+}
+
+pub fn format_answers(answers: &BTreeMap<String, Answers>) -> String {
+    let mut output = String::new();
+    for (name, answer) in answers {
+        output += &format!(
+            "{} {} {}\n",
+            name,
+            answer.part_1.as_deref().unwrap_or(UNKNOWN_ANSWER),
+            answer.part_2.as_deref().unwrap_or(UNKNOWN_ANSWER)
+        );
+    }
+    output
+}
+
+pub fn write_answers(filename: &Path, answers: &BTreeMap<String, Answers>) {
+    fs::write(filename, format_answers(answers)).expect("Could not write answers!");
+}
+
+/// Reads the answers store, a missing store simply has no answers
+pub fn read_answers(filename: &Path) -> BTreeMap<String, Answers> {
+    if filename.exists() {
+        parse_answers(&read_input_to_vector(filename.to_str().unwrap()))
+    } else {
+        BTreeMap::new()
+    }
+}
+
+/// Every year has its own directory `data/<year>` with the puzzle inputs, examples and answers
+pub fn year_directory(year: u32) -> PathBuf {
+    Path::new(DATA_DIRECTORY).join(year.to_string())
+}
+
+pub fn examples_directory(year: u32) -> PathBuf {
+    year_directory(year).join(EXAMPLES_DIRECTORY)
+}
+
+pub fn answers_file(year: u32) -> PathBuf {
+    year_directory(year).join(ANSWERS_FILE)
+}
+
+pub fn puzzle_input(year: u32, day: u32) -> PathBuf {
+    year_directory(year).join(format!("{}.txt", day_name(day)))
+}
+
+pub fn day_name(day: u32) -> String {
+    format!("day{}", day)
+}
+
+/// The day of an input name like `day5` or `day5-1`
+pub fn parse_day_name(name: &str) -> Option<u32> {
+    name.strip_prefix("day")?.split("-").next()?.parse().ok()
+}
+
+/// The name under which the answers of an input file are stored
+pub fn input_name(path: &Path) -> String {
+    path.file_stem()
+        .expect("Input file has no name")
+        .to_string_lossy()
+        .to_string()
+}
+
+/// All inputs of a day: the puzzle input `data/<year>/day<n>.txt` and the examples `data/<year>/examples/day<n>-<k>.txt`
+pub fn input_files(year: u32, day: u32) -> Vec<PathBuf> {
+    let mut files = vec![];
+    let puzzle_input = puzzle_input(year, day);
+    if puzzle_input.is_file() {
+        files.push(puzzle_input);
+    }
+
+    let mut examples = vec![];
+    if let Ok(entries) = fs::read_dir(examples_directory(year)) {
+        for entry in entries.flatten() {
+            let path = entry.path();
+            let name = input_name(&path);
+            let is_example = name.contains("-") && parse_day_name(&name) == Some(day);
+            if is_example && path.extension().is_some_and(|e| e == "txt") {
+                examples.push(path);
+            }
+        }
+    }
+    examples.sort();
+    files.extend(examples);
+    files
+}
+
+/// All puzzle inputs of all years as year, day and file
+pub fn puzzle_inputs() -> Vec<(u32, u32, PathBuf)> {
+    let mut inputs = vec![];
+    for year in YEARS {
+        for entry in fs::read_dir(year_directory(year))
+            .into_iter()
+            .flatten()
+            .flatten()
+        {
+            let path = entry.path();
+            // Skips the examples directory and the answers store
+            if let Some(day) = parse_day_name(&input_name(&path)).filter(|_| path.is_file()) {
+                inputs.push((year, day, path));
+            }
+        }
+    }
+    inputs.sort();
+    inputs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::options::Options;
+    use crate::registry::solve_day;
+
+    #[test]
+    fn test_answers_store() {
+        let input = vec![
+            "# day, part 1, part 2".to_string(),
+            "day5-1 35 46".to_string(),
+            "".to_string(),
+            "day8-1 2 -".to_string(),
+        ];
+        let answers = parse_answers(&input);
+        assert_eq!(2, answers.len());
+        assert_eq!(
+            Answers {
+                part_1: Some("35".to_string()),
+                part_2: Some("46".to_string())
+            },
+            answers["day5-1"]
+        );
+        assert_eq!(None, answers["day8-1"].part_2);
+        assert_eq!(Some(8), parse_day_name("day8-1"));
+        assert_eq!(
+            "day5-1 35 46\nday8-1 2 -\n".to_string(),
+            format_answers(&answers)
+        );
+    }
+
+    /// Checks every example fixture that has a stored answer, so importing a puzzle page is enough to test a day
+    #[test]
+    fn test_examples() {
+        for year in YEARS {
+            let answers = read_answers(&answers_file(year));
+            for (name, expected) in answers.iter() {
+                let path = examples_directory(year).join(format!("{}.txt", name));
+                if !path.is_file() {
+                    continue;
+                }
+                let day = parse_day_name(name).unwrap();
+                let input = read_input_to_vector(path.to_str().unwrap());
+                let (output_1, output_2) = solve_day(year, day, &input, &Options::default())
+                    .expect("No solution for example")
+                    .unwrap()
+                    .answers;
+                if let Some(result_1) = &expected.part_1 {
+                    assert_eq!(result_1, &output_1, "part 1 of {} {}", year, name);
+                }
+                if let Some(result_2) = &expected.part_2 {
+                    assert_eq!(result_2, &output_2, "part 2 of {} {}", year, name);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use regex::Regex;
+use std::fs;
+
+use crate::fixtures::{self, Answers};
+
+/// An example input of a puzzle page together with the answers given in the puzzle text
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub input: Vec<String>,
+    pub answers: Answers,
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Extracts the examples from a saved puzzle page. Every part is an `<article>`, we take
+/// the first `<pre><code>` block of a part as its example and the last emphasized code
+/// (`<code><em>42</em></code>`) as its answer. If the second part has no example of its own,
+/// its answer belongs to the example of the first part.
+pub fn extract_examples(html: &str) -> Vec<Example> {
+    let article_regex = Regex::new(r"(?s)<article[^>]*>(.*?)</article>").unwrap();
+    let example_regex = Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").unwrap();
+    let answer_regex = Regex::new(r"<code><em>([^<]*)</em></code>").unwrap();
+    let tag_regex = Regex::new(r"<[^>]*>").unwrap();
+
+    let mut examples: Vec<Example> = vec![];
+    for (part, article) in article_regex.captures_iter(html).take(2).enumerate() {
+        let article = &article[1];
+        let answer = answer_regex
+            .captures_iter(article)
+            .last()
+            .map(|c| unescape_html(&c[1]));
+
+        if let Some(example) = example_regex.captures(article) {
+            // Examples can contain highlighting like <em>, so we strip all tags from the block
+            let text = unescape_html(&tag_regex.replace_all(&example[1], ""));
+            examples.push(Example {
+                input: text.lines().map(|l| l.to_string()).collect(),
+                answers: Answers::default(),
+            });
+        }
+        if let Some(example) = examples.last_mut() {
+            if part == 0 {
+                example.answers.part_1 = answer;
+            } else {
+                example.answers.part_2 = answer;
+            }
+        }
+    }
+    examples
+}
+
+/// Imports the examples of a saved puzzle page as fixtures `data/<year>/examples/day<n>-<k>.txt`
+/// and adds their answers to the answers store. Re-importing a page overwrites its fixtures.
+pub fn import(year: u32, day: u32, filename: &str) {
+    let html = fs::read_to_string(filename).expect("Could not read file!");
+    let examples = extract_examples(&html);
+    if examples.is_empty() {
+        println!("No examples found in {}", filename);
+        return;
+    }
+
+    let examples_directory = fixtures::examples_directory(year);
+    fs::create_dir_all(&examples_directory).expect("Could not create examples directory!");
+    let answers_file = fixtures::answers_file(year);
+    let mut answers = fixtures::read_answers(&answers_file);
+    for (index, example) in examples.into_iter().enumerate() {
+        let name = format!("{}-{}", fixtures::day_name(day), index + 1);
+        let path = examples_directory.join(format!("{}.txt", name));
+        fs::write(&path, example.input.join("\n") + "\n").expect("Could not write example!");
+        println!(
+            "{}: part 1: {} - part 2: {}",
+            path.display(),
+            example.answers.part_1.as_deref().unwrap_or("-"),
+            example.answers.part_2.as_deref().unwrap_or("-")
+        );
+        answers.insert(name, example.answers);
+    }
+    fixtures::write_answers(&answers_file, &answers);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_extract_examples() {
+        let html = r#"<main>
+<article class="day-desc"><h2>--- Day 8: Haunted Wasteland ---</h2>
+<p>For example:</p>
+<pre><code>RL
+
+AAA = (BBB, CCC)
+<em>ZZZ</em> = (ZZZ, ZZZ)
+</code></pre>
+<p>Starting with <code>AAA</code>, you need to go <code><em>2</em></code> steps.</p>
+</article>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>LR
+
+11A = (11B, XXX)
+</code></pre>
+<p>It takes <code>6</code> steps, so <code><em>6</em></code> steps &amp; done.</p>
+</article>
+</main>"#;
+        let examples = extract_examples(html);
+        assert_eq!(
+            vec![
+                Example {
+                    input: vec![
+                        "RL".to_string(),
+                        "".to_string(),
+                        "AAA = (BBB, CCC)".to_string(),
+                        "ZZZ = (ZZZ, ZZZ)".to_string(),
+                    ],
+                    answers: Answers {
+                        part_1: Some("2".to_string()),
+                        part_2: None,
+                    },
+                },
+                Example {
+                    input: vec![
+                        "LR".to_string(),
+                        "".to_string(),
+                        "11A = (11B, XXX)".to_string()
+                    ],
+                    answers: Answers {
+                        part_1: None,
+                        part_2: Some("6".to_string()),
+                    },
+                },
+            ],
+            examples
+        );
+    }
+}
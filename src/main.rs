@@ -1,145 +1,99 @@
-#![feature(array_chunks)]
 #![feature(test)]
 mod my_io;
+mod options;
 use my_io::read_input_to_vector;
-use std::{env, fs};
+use options::Options;
+use std::env;
 
-mod day1;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
+mod fixtures;
+mod import;
+mod profile;
+mod registry;
+mod repl;
+#[cfg(test)]
+mod testing;
+mod watch;
+mod year2023;
 
-fn run(day: &String, input: &String) {
-    match day.as_str() {
-        "day1" => {
-            let input = &read_input_to_vector(&input);
-            let result = day1::part_one(input);
-            let result_2 = day1::part_two(input);
-            println!(
-                "Sum of all calibrations: part 1: {} - part 2: {}",
-                result, result_2
-            );
-        }
-        "day2" => {
-            let (result_1, result_2) = day2::solve(&read_input_to_vector(&input));
-            println!(
-                "Sum of all IDs: {} - and the sum of power: {}",
-                result_1, result_2
-            );
-        }
-        "day3" => {
-            let (result_1, result_2) = day3::solve(&read_input_to_vector(&input));
-            println!(
-                "Sum of all part numbers: {} - sum of all gear ratios: {}",
-                result_1, result_2
-            );
-        }
-        "day4" => {
-            let (result_1, result_2) = day4::solve(&read_input_to_vector(&input));
-            println!("total points: {} - total cards: {}", result_1, result_2);
-        }
-        "day5" => {
-            let (result_1, result_2) = day5::solve(&read_input_to_vector(&input));
-            println!(
-                "Lowest location number: {} - lowest number for ranges: {}",
-                result_1, result_2
-            );
-        }
-        "day6" => {
-            let (result_1, result_2) = day6::solve(&read_input_to_vector(&input));
-            println!(
-                "Win product: {} - ways to win the long race: {}",
-                result_1, result_2
-            );
-        }
-        "day7" => {
-            let (result_1, result_2) = day7::solve(&read_input_to_vector(&input));
-            println!(
-                "Total winnings w/ jacks: {} - and w/ jokers: {}",
-                result_1, result_2
-            );
-        }
-        "day8" => {
-            let (result_1, result_2) = day8::solve(&read_input_to_vector(&input));
-            println!(
-                "Steps to reach ZZZ: {} - Steps to reach **Z: {}",
-                result_1, result_2
-            );
-        }
-        "day9" => {
-            let (result_1, result_2) = day9::solve(&read_input_to_vector(&input));
-            println!(
-                "Sum of extrapolated next values {} - previous values {}",
-                result_1, result_2
-            );
-        }
-        "day10" => {
-            let (result_1, result_2) = day10::solve(&read_input_to_vector(&input));
-            println!("Maximum distance {} - inner tiles {}", result_1, result_2);
-        }
-        "day11" => {
-            let (result_1, result_2) = day11::solve(&read_input_to_vector(&input));
-            println!(
-                "Distance sum {} - larger distance sum {}",
-                result_1, result_2
-            );
-        }
-        "day12" => {
-            let (result_1, result_2) = day12::solve(&read_input_to_vector(&input));
-            println!("Distance sum {} - inner tiles {}", result_1, result_2);
-        }
-        "day13" => {
-            let (result_1, result_2) = day13::solve(&read_input_to_vector(&input));
+#[cfg(feature = "alloc-profile")]
+#[global_allocator]
+static ALLOCATOR: profile::CountingAllocator = profile::CountingAllocator;
+
+/// Days given as `dayn` without a year belong to the first event we solved
+const DEFAULT_YEAR: u32 = 2023;
+
+fn run(year: u32, day: u32, input: &str, options: &Options) {
+    match registry::solve_day(year, day, &read_input_to_vector(input), options) {
+        Some(Ok(solution)) => {
+            solution.report.iter().for_each(|line| println!("{}", line));
+            let (result_1, result_2) = &solution.answers;
             println!(
-                "Reflection sum {} - smudge reflection sum {}",
-                result_1, result_2
-            );
-        }
-        _ => {
-            println!("No solution for this day yet!")
-        }
+                "{} day {}: {}",
+                year,
+                day,
+                registry::describe(year, day, result_1, result_2)
+            )
+        }
+        Some(Err(message)) => println!("{} day {} failed:\n{}", year, day, message),
+        None => println!("No solution for this day yet!"),
     }
 }
-fn main() {
-    let args = env::args().collect::<Vec<String>>();
-    if args.len() < 3 {
-        let directory_path = "data";
-        let mut data_files = vec![];
 
-        // Read the directory contents
-        if let Ok(entries) = fs::read_dir(directory_path) {
-            for entry in entries {
-                if let Ok(entry) = entry {
-                    // Get the file name
-                    let file_name = entry.file_name();
-                    data_files.push(file_name);
+fn parse_year(argument: Option<&String>) -> u32 {
+    argument
+        .expect("Please specify the year!")
+        .parse()
+        .expect("The year must be a number!")
+}
+
+fn parse_day(argument: Option<&String>) -> u32 {
+    argument
+        .expect("Please specify the day!")
+        .parse()
+        .expect("The day must be a number!")
+}
+
+fn main() {
+    let (args, options) = Options::parse(&env::args().collect::<Vec<String>>());
+    match args.get(1).map(|a| a.as_str()) {
+        None => {
+            for (year, day, input) in fixtures::puzzle_inputs() {
+                run(year, day, input.to_str().unwrap(), &options);
+            }
+        }
+        Some("run") => {
+            let year = parse_year(args.get(2));
+            let day = parse_day(args.get(3));
+            let input = match args.get(4) {
+                Some(input) => input.clone(),
+                None => fixtures::puzzle_input(year, day)
+                    .to_string_lossy()
+                    .to_string(),
+            };
+            run(year, day, &input, &options);
+        }
+        Some("watch") => watch::watch(parse_year(args.get(2)), parse_day(args.get(3)), &options),
+        Some("import") => {
+            let page = args.get(4).expect("Please specify the saved puzzle page!");
+            import::import(parse_year(args.get(2)), parse_day(args.get(3)), page);
+        }
+        Some("repl") => repl::repl(&args[2..], &options),
+        Some("profile") => {
+            let year = args.get(2).map(|y| parse_year(Some(y)));
+            let day = args.get(3).map(|d| parse_day(Some(d)));
+            for (input_year, input_day, input) in fixtures::puzzle_inputs() {
+                if year.is_none_or(|y| y == input_year) && day.is_none_or(|d| d == input_day) {
+                    profile::profile(input_year, input_day, input.to_str().unwrap(), &options);
                 }
             }
-        } else {
-            println!("Failed to read directory");
         }
-
-        for file in data_files {
-            let file_name = file.to_str().unwrap();
-            let day = file_name.split(".").collect::<Vec<&str>>()[0];
-            let input = format!("{}/{}", directory_path, file_name);
-            run(&day.to_string(), &input);
+        Some(day) => {
+            // Short form `dayn input` for the default year
+            let day = fixtures::parse_day_name(day).expect("Unknown command or day!");
+            let input = args
+                .get(2)
+                .expect("Please specify input as second argument!");
+            run(DEFAULT_YEAR, day, input, &options);
         }
-    } else {
-        let day = args.get(1).expect("Please specify day as first argument!");
-        let input = args
-            .get(2)
-            .expect("Please specify input as second argument!");
-
-        run(&day, &input);
     }
 }
@@ -0,0 +1,54 @@
+use std::collections::BTreeMap;
+
+/// Command line options passed on to the days, given as `--name=value` or as flag `--name`
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    values: BTreeMap<String, Option<String>>,
+}
+
+impl Options {
+    /// Splits the arguments into positional arguments and options
+    pub fn parse(args: &[String]) -> (Vec<String>, Options) {
+        let mut positional = vec![];
+        let mut options = Options::default();
+        for arg in args {
+            match arg.strip_prefix("--") {
+                Some(option) => {
+                    let mut option_split = option.splitn(2, "=");
+                    let name = option_split.next().unwrap().to_string();
+                    let value = option_split.next().map(|v| v.to_string());
+                    options.values.insert(name, value);
+                }
+                None => positional.push(arg.clone()),
+            }
+        }
+        (positional, options)
+    }
+
+    pub fn get(self: &Options, name: &str) -> Option<&str> {
+        self.values.get(name)?.as_deref()
+    }
+
+    pub fn is_set(self: &Options, name: &str) -> bool {
+        self.values.contains_key(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options() {
+        let args = ["run", "2023", "--vocabulary=german", "1", "--tens"]
+            .iter()
+            .map(|a| a.to_string())
+            .collect::<Vec<String>>();
+        let (positional, options) = Options::parse(&args);
+        assert_eq!(vec!["run", "2023", "1"], positional);
+        assert_eq!(Some("german"), options.get("vocabulary"));
+        assert!(options.is_set("tens"));
+        assert_eq!(None, options.get("tens"));
+        assert!(!options.is_set("words"));
+    }
+}
@@ -0,0 +1,196 @@
+#[cfg(feature = "alloc-profile")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+use crate::my_io::read_input_to_vector;
+use crate::options::Options;
+use crate::registry::{parse_day, solve_day};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED_BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+static PEAK_LIVE_BYTES: AtomicUsize = AtomicUsize::new(0);
+
+/// Wraps the system allocator and counts allocations, allocated bytes and live bytes.
+/// Only installed as global allocator with the `alloc-profile` feature, as the counting is not free.
+#[cfg(feature = "alloc-profile")]
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-profile")]
+impl CountingAllocator {
+    fn record_allocation(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED_BYTES.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE_BYTES.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK_LIVE_BYTES.fetch_max(live, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "alloc-profile")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            Self::record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            // A reallocation counts as a new allocation of the full new size
+            LIVE_BYTES.fetch_sub(layout.size(), Ordering::Relaxed);
+            Self::record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocationStats {
+    pub allocations: usize,
+    pub allocated_bytes: usize,
+    /// Highest number of live bytes above the live bytes at the start of the measurement
+    pub peak_live_bytes: usize,
+}
+
+pub struct Measurement<T> {
+    pub result: T,
+    pub duration: Duration,
+    /// None if the counting allocator is not installed
+    pub allocations: Option<AllocationStats>,
+}
+
+/// Runs f and measures its duration and, with the `alloc-profile` feature, its allocations
+pub fn measure<T, F: FnOnce() -> T>(f: F) -> Measurement<T> {
+    let allocations_start = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated_bytes_start = ALLOCATED_BYTES.load(Ordering::Relaxed);
+    let live_bytes_start = LIVE_BYTES.load(Ordering::Relaxed);
+    PEAK_LIVE_BYTES.store(live_bytes_start, Ordering::Relaxed);
+
+    let start = Instant::now();
+    let result = f();
+    let duration = start.elapsed();
+
+    let allocations = if cfg!(feature = "alloc-profile") {
+        Some(AllocationStats {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations_start,
+            allocated_bytes: ALLOCATED_BYTES.load(Ordering::Relaxed) - allocated_bytes_start,
+            // Other threads can free memory in the meantime, so the peak can end up below the start
+            peak_live_bytes: PEAK_LIVE_BYTES
+                .load(Ordering::Relaxed)
+                .saturating_sub(live_bytes_start),
+        })
+    } else {
+        None
+    };
+
+    Measurement {
+        result,
+        duration,
+        allocations,
+    }
+}
+
+fn format_bytes(bytes: usize) -> String {
+    if bytes < 1024 {
+        format!("{} B", bytes)
+    } else if bytes < 1024 * 1024 {
+        format!("{:.1} KiB", bytes as f64 / 1024.0)
+    } else {
+        format!("{:.1} MiB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+fn format_measurement<T>(year: u32, day: u32, phase: &str, measurement: &Measurement<T>) -> String {
+    let mut output = format!(
+        "{} day{:<2} {:<6} {:>10.3} ms",
+        year,
+        day,
+        phase,
+        measurement.duration.as_secs_f64() * 1000.0
+    );
+    if let Some(stats) = measurement.allocations {
+        output += &format!(
+            " {:>9} allocations {:>10} allocated {:>10} peak",
+            stats.allocations,
+            format_bytes(stats.allocated_bytes),
+            format_bytes(stats.peak_live_bytes)
+        );
+    }
+    output
+}
+
+/// Prints timings and, with the `alloc-profile` feature, allocation statistics for reading the input,
+/// the day's own parsing and each part. Days that compute both parts together are measured as one
+/// solve phase that includes their parsing.
+pub fn profile(year: u32, day: u32, input: &str, options: &Options) {
+    let read = measure(|| read_input_to_vector(input));
+    println!("{}", format_measurement(year, day, "read", &read));
+
+    let parse = measure(|| parse_day(year, day, &read.result, options));
+    match &parse.result {
+        Some(Ok(parts)) => {
+            println!("{}", format_measurement(year, day, "parse", &parse));
+            let part_1 = measure(|| parts.part_1());
+            let part_2 = measure(|| parts.part_2());
+            for (phase, part) in [("part 1", &part_1), ("part 2", &part_2)] {
+                match &part.result {
+                    Ok(_) => println!("{}", format_measurement(year, day, phase, part)),
+                    Err(message) => println!("{} day {} {} failed:\n{}", year, day, phase, message),
+                }
+            }
+            return;
+        }
+        Some(Err(message)) => {
+            println!("{} day {} failed:\n{}", year, day, message);
+            return;
+        }
+        None => {}
+    }
+
+    let solve = measure(|| solve_day(year, day, &read.result, options));
+    match solve.result {
+        Some(Ok(_)) => println!("{}", format_measurement(year, day, "solve", &solve)),
+        Some(Err(message)) => println!("{} day {} failed:\n{}", year, day, message),
+        None => println!("No solution for this day yet!"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let measurement = measure(|| vec![0u64; 1024].len());
+        assert_eq!(1024, measurement.result);
+        if let Some(stats) = measurement.allocations {
+            assert!(stats.allocations >= 1);
+            assert!(stats.allocated_bytes >= 8 * 1024);
+            assert!(stats.peak_live_bytes >= 8 * 1024);
+        }
+        assert_eq!("1.5 KiB", format_bytes(1536));
+    }
+
+    #[test]
+    fn test_parts() {
+        let input = vec![
+            "Time:      7  15   30".to_string(),
+            "Distance:  9  40  200".to_string(),
+        ];
+        let options = Options::default();
+        let parts = parse_day(2023, 6, &input, &options).unwrap().unwrap();
+        assert_eq!(Ok("288".to_string()), parts.part_1());
+        assert_eq!(Ok("71503".to_string()), parts.part_2());
+        // The inner tiles of day 10 need the loop of part 1
+        assert!(parse_day(2023, 10, &input, &options).is_none());
+    }
+}
@@ -0,0 +1,86 @@
+use crate::options::Options;
+use crate::repl::Explore;
+use crate::year2023;
+
+/// All event years with solutions
+pub const YEARS: [u32; 1] = [2023];
+
+/// The answers of a day as strings, with the report lines requested by options like `--trace`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Solution {
+    pub answers: (String, String),
+    /// Printed before the answers
+    pub report: Vec<String>,
+}
+
+/// Solves a day and returns both answers as strings, or None if there is no solution for this day yet.
+/// Days that validate their input return an error message for invalid inputs instead of panicking.
+/// The options are passed on to the days that can be configured from the command line.
+pub fn solve_day(
+    year: u32,
+    day: u32,
+    input: &Vec<String>,
+    options: &Options,
+) -> Option<Result<Solution, String>> {
+    match year {
+        2023 => year2023::solve_day(day, input, options),
+        _ => None,
+    }
+}
+
+/// The parsed input of a day whose parts can be solved, and profiled, on their own
+pub trait Parts {
+    fn part_1(&self) -> Result<String, String>;
+
+    fn part_2(&self) -> Result<String, String>;
+}
+
+/// Parses the input of a day for solving the parts separately, None if there is no solution for this
+/// day yet or the day only solves both parts together
+pub fn parse_day<'a>(
+    year: u32,
+    day: u32,
+    input: &'a Vec<String>,
+    options: &Options,
+) -> Option<Result<Box<dyn Parts + 'a>, String>> {
+    match year {
+        2023 => year2023::parse_day(day, input, options),
+        _ => None,
+    }
+}
+
+/// The parsed puzzle state of a day that can be queried in the REPL, or why the input cannot be parsed
+pub fn explore(
+    year: u32,
+    day: u32,
+    input: &Vec<String>,
+    options: &Options,
+) -> Option<Result<Box<dyn Explore>, String>> {
+    match year {
+        2023 => year2023::explore(day, input, options),
+        _ => None,
+    }
+}
+
+/// A human readable description of the answers of a day
+pub fn describe(year: u32, day: u32, result_1: &String, result_2: &String) -> String {
+    match year {
+        2023 => year2023::describe(day, result_1, result_2),
+        _ => format!("part 1: {} - part 2: {}", result_1, result_2),
+    }
+}
+
+pub fn to_answers<T: ToString, U: ToString>((result_1, result_2): (T, U)) -> Solution {
+    Solution {
+        answers: (result_1.to_string(), result_2.to_string()),
+        report: vec![],
+    }
+}
+
+/// For days that return their report lines along with the answers
+pub fn with_report<T: ToString, U: ToString>((answers, report): ((T, U), Vec<String>)) -> Solution {
+    Solution {
+        report,
+        ..to_answers(answers)
+    }
+}
@@ -0,0 +1,236 @@
+use std::io::{self, BufRead, Write};
+use std::panic;
+use std::path::Path;
+
+use crate::fixtures;
+use crate::my_io::read_input_to_vector;
+use crate::options::Options;
+use crate::registry;
+
+/// Parsed puzzle state of a day that can be queried from the REPL
+pub trait Explore {
+    /// One line per query with its arguments and what it does
+    fn help(&self) -> Vec<&'static str>;
+
+    /// Answers a query, None if the command is not known for this day
+    fn query(&self, command: &str, arguments: &[&str]) -> Option<String>;
+}
+
+struct Session {
+    year: u32,
+    day: u32,
+    input: Vec<String>,
+    options: Options,
+    explorer: Option<Box<dyn Explore>>,
+}
+
+impl Session {
+    /// Fails if the day can be explored, but the input cannot be parsed
+    fn new(year: u32, day: u32, input: Vec<String>, options: Options) -> Result<Session, String> {
+        let explorer = registry::explore(year, day, &input, &options).transpose()?;
+        Ok(Session {
+            year,
+            day,
+            input,
+            options,
+            explorer,
+        })
+    }
+}
+
+const HELP: [&str; 4] = [
+    "load <year> <day> [input]  loads the input of a day, by default data/<year>/day<n>.txt",
+    "solve                      solves both parts for the loaded input",
+    "help                       shows this help and the queries of the loaded day",
+    "quit                       leaves the REPL",
+];
+
+fn load(arguments: &[&str], options: &Options) -> Result<Session, String> {
+    let year = arguments
+        .first()
+        .and_then(|y| y.parse().ok())
+        .ok_or("Please specify the year as number")?;
+    let day = arguments
+        .get(1)
+        .and_then(|d| d.parse().ok())
+        .ok_or("Please specify the day as number")?;
+    let filename = match arguments.get(2) {
+        Some(filename) => filename.to_string(),
+        None => fixtures::puzzle_input(year, day)
+            .to_string_lossy()
+            .to_string(),
+    };
+    if !Path::new(&filename).is_file() {
+        return Err(format!("Could not read {}", filename));
+    }
+    Session::new(year, day, read_input_to_vector(&filename), options.clone())
+}
+
+/// Runs f and returns the message of a panic as error. Days that do not validate their input yet
+/// can panic on malformed input, which should not end the session.
+fn catch_panic<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(f));
+    panic::set_hook(hook);
+    result.map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_default();
+        format!("Cannot handle the input: {}", message)
+    })
+}
+
+/// Executes one line of input and returns the output to print
+fn execute(session: &mut Option<Session>, line: &str, options: &Options) -> String {
+    let words = line.split_whitespace().collect::<Vec<&str>>();
+    let Some((&command, arguments)) = words.split_first() else {
+        return String::new();
+    };
+
+    match (command, session.as_ref()) {
+        ("load", _) => match load(arguments, options) {
+            Ok(loaded) => {
+                let output = format!(
+                    "Loaded {} lines for {} day {}",
+                    loaded.input.len(),
+                    loaded.year,
+                    loaded.day
+                );
+                *session = Some(loaded);
+                output
+            }
+            Err(message) => message,
+        },
+        ("help", _) => {
+            let mut lines = HELP.to_vec();
+            if let Some(explorer) = session.as_ref().and_then(|s| s.explorer.as_ref()) {
+                lines.extend(explorer.help());
+            }
+            lines.join("\n")
+        }
+        (_, None) => "Please load a day first, e.g. load 2023 5".to_string(),
+        ("solve", Some(loaded)) => {
+            let solution = catch_panic(|| {
+                registry::solve_day(loaded.year, loaded.day, &loaded.input, &loaded.options)
+            });
+            match solution.unwrap_or_else(|message| Some(Err(message))) {
+                Some(Ok(solution)) => {
+                    let (result_1, result_2) = &solution.answers;
+                    let mut lines = solution.report.clone();
+                    lines.push(registry::describe(
+                        loaded.year,
+                        loaded.day,
+                        result_1,
+                        result_2,
+                    ));
+                    lines.join("\n")
+                }
+                Some(Err(message)) => message,
+                None => "No solution for this day yet!".to_string(),
+            }
+        }
+        (_, Some(loaded)) => {
+            let output = catch_panic(|| {
+                loaded
+                    .explorer
+                    .as_ref()
+                    .and_then(|e| e.query(command, arguments))
+            });
+            match output {
+                Ok(Some(output)) => output,
+                Ok(None) => format!("Unknown command {}, try help", command),
+                Err(message) => message,
+            }
+        }
+    }
+}
+
+/// Starts an interactive shell on stdin. The first input can be given right away, e.g. `repl 2023 5`.
+/// The options are used when solving the loaded days.
+pub fn repl(arguments: &[String], options: &Options) {
+    let mut session = None;
+    if !arguments.is_empty() {
+        println!(
+            "{}",
+            execute(
+                &mut session,
+                &format!("load {}", arguments.join(" ")),
+                options
+            )
+        );
+    }
+
+    let stdin = io::stdin();
+    loop {
+        print!("> ");
+        io::stdout().flush().unwrap();
+        let mut line = String::new();
+        if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+            break;
+        }
+        let line = line.trim();
+        if line == "quit" || line == "exit" {
+            break;
+        }
+        let output = execute(&mut session, line, options);
+        if !output.is_empty() {
+            println!("{}", output);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_repl() {
+        let options = Options::default();
+        let mut session = None;
+        assert_eq!(
+            "Please load a day first, e.g. load 2023 5",
+            execute(&mut session, "solve", &options)
+        );
+        session = Session::new(
+            2023,
+            6,
+            vec![
+                "Time:      7  15   30".to_string(),
+                "Distance:  9  40  200".to_string(),
+            ],
+            Options::default(),
+        )
+        .ok();
+        assert_eq!(
+            "Win product: 288 - ways to win the long race: 71503",
+            execute(&mut session, "solve", &options)
+        );
+        assert_eq!(
+            "Unknown command foo, try help",
+            execute(&mut session, "foo", &options)
+        );
+        // Days without input validation cannot end the session either
+        session = Session::new(2023, 9, vec!["1 x 3".to_string()], Options::default()).ok();
+        assert!(execute(&mut session, "solve", &options).starts_with("Cannot handle the input: "));
+        session = Session::new(
+            2023,
+            6,
+            vec!["Time: 7".to_string(), "Distance: 9 40".to_string()],
+            Options::default(),
+        )
+        .ok();
+        assert_eq!(
+            "There are 1 times but 2 distances",
+            execute(&mut session, "solve", &options)
+        );
+
+        // An input that cannot be explored is reported instead of ending the session
+        assert_eq!(
+            Err("The almanac is empty".to_string()),
+            Session::new(2023, 5, vec![], Options::default()).map(|_| ())
+        );
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt::Debug;
+
+/// Pseudo random numbers from a linear congruential generator, always seeded the same so a
+/// failing generated case shows up in every run
+pub struct Random {
+    state: u64,
+}
+
+impl Random {
+    pub fn new(seed: u64) -> Random {
+        Random { state: seed }
+    }
+
+    /// A number from 0 to limit - 1
+    pub fn below(&mut self, limit: u64) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.state >> 33) % limit
+    }
+}
+
+/// Checks that formatting a generated value and parsing it again gives the same value
+pub fn check_round_trip<T, E>(
+    cases: usize,
+    mut generate: impl FnMut(&mut Random) -> T,
+    format: impl Fn(&T) -> String,
+    parse: impl Fn(&str) -> Result<T, E>,
+) where
+    T: Debug + PartialEq,
+    E: Debug,
+{
+    let mut random = Random::new(42);
+    for _ in 0..cases {
+        let value = generate(&mut random);
+        let text = format(&value);
+        match parse(&text) {
+            Ok(parsed) => assert_eq!(value, parsed, "round trip of {}", text),
+            Err(error) => panic!("cannot parse {}: {:?}", text, error),
+        }
+    }
+}
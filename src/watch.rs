@@ -0,0 +1,138 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::panic;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+use crate::fixtures::{self, Answers};
+use crate::my_io::read_input_to_vector;
+use crate::options::Options;
+use crate::registry::solve_day;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Polls the input and example files of a day and re-runs the day whenever one of them
+/// (or the answers store) changes. New, removed and modified files all count as a change.
+pub fn watch(year: u32, day: u32, options: &Options) {
+    let mut last_modified = BTreeMap::new();
+    let mut previous_answers = BTreeMap::new();
+    println!(
+        "Watching inputs of {} day {}, press Ctrl+C to stop",
+        year, day
+    );
+    loop {
+        let mut watched_files = fixtures::input_files(year, day);
+        watched_files.push(fixtures::answers_file(year));
+        let modified = modification_times(&watched_files);
+        if modified != last_modified {
+            last_modified = modified;
+            rerun(year, day, options, &mut previous_answers);
+        }
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+fn modification_times(files: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    files
+        .iter()
+        .filter_map(|f| {
+            let modified = fs::metadata(f).and_then(|m| m.modified()).ok()?;
+            Some((f.clone(), modified))
+        })
+        .collect()
+}
+
+fn rerun(
+    year: u32,
+    day: u32,
+    options: &Options,
+    previous_answers: &mut BTreeMap<String, (String, String)>,
+) {
+    let expected_answers = fixtures::read_answers(&fixtures::answers_file(year));
+    let files = fixtures::input_files(year, day);
+    if files.is_empty() {
+        println!("No input files found for {} day {}", year, day);
+        return;
+    }
+
+    println!("--- {} day {} ---", year, day);
+    for file in files {
+        let name = fixtures::input_name(&file);
+        let input = read_input_to_vector(file.to_str().unwrap());
+        // A panicking solution must not end the watch, we just report it and wait for the next change
+        let result = panic::catch_unwind(|| solve_day(year, day, &input, options));
+        match result {
+            Ok(Some(Ok(solution))) => {
+                solution.report.iter().for_each(|line| println!("{}", line));
+                let answers = solution.answers;
+                let expected = expected_answers.get(&name).cloned().unwrap_or_default();
+                println!(
+                    "{}",
+                    report(&name, &answers, previous_answers.get(&name), &expected)
+                );
+                previous_answers.insert(name, answers);
+            }
+            Ok(Some(Err(message))) => println!("{}: failed:\n{}", name, message),
+            Ok(None) => {
+                println!("No solution for this day yet!");
+                return;
+            }
+            Err(_) => println!("{}: solution panicked", name),
+        }
+    }
+}
+
+/// Describes one part answer relative to the previous run and the expected answer, e.g.
+/// `35 (was 34) ok` or `35 wrong, expected 46`
+fn compare(current: &String, previous: Option<&String>, expected: Option<&String>) -> String {
+    let mut description = current.clone();
+    if let Some(previous) = previous {
+        if previous != current {
+            description += &format!(" (was {})", previous);
+        }
+    }
+    match expected {
+        Some(expected) if expected == current => description += " ok",
+        Some(expected) => description += &format!(" wrong, expected {}", expected),
+        None => {}
+    }
+    description
+}
+
+fn report(
+    name: &str,
+    answers: &(String, String),
+    previous: Option<&(String, String)>,
+    expected: &Answers,
+) -> String {
+    format!(
+        "{}: part 1: {} - part 2: {}",
+        name,
+        compare(&answers.0, previous.map(|p| &p.0), expected.part_1.as_ref()),
+        compare(&answers.1, previous.map(|p| &p.1), expected.part_2.as_ref())
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_watch_report() {
+        let expected = Answers {
+            part_1: Some("35".to_string()),
+            part_2: Some("46".to_string()),
+        };
+        let answers = ("35".to_string(), "47".to_string());
+        let previous = ("34".to_string(), "47".to_string());
+        assert_eq!(
+            "day5-1: part 1: 35 (was 34) ok - part 2: 47 wrong, expected 46",
+            report("day5-1", &answers, Some(&previous), &expected)
+        );
+        assert_eq!(
+            "day5-1: part 1: 35 - part 2: 47",
+            report("day5-1", &answers, None, &Answers::default())
+        );
+    }
+}
@@ -0,0 +1,785 @@
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use crate::options::Options;
+use crate::registry::Parts;
+
+const DIGITS: [(&str, u32); 10] = [
+    ("0", 0),
+    ("1", 1),
+    ("2", 2),
+    ("3", 3),
+    ("4", 4),
+    ("5", 5),
+    ("6", 6),
+    ("7", 7),
+    ("8", 8),
+    ("9", 9),
+];
+
+/// Words with their values, like the tables below
+type WordTable = &'static [(&'static str, u32)];
+
+const ENGLISH_WORDS: [(&str, u32); 9] = [
+    ("one", 1),
+    ("two", 2),
+    ("three", 3),
+    ("four", 4),
+    ("five", 5),
+    ("six", 6),
+    ("seven", 7),
+    ("eight", 8),
+    ("nine", 9),
+];
+
+const ENGLISH_TENS: [(&str, u32); 18] = [
+    ("ten", 10),
+    ("eleven", 11),
+    ("twelve", 12),
+    ("thirteen", 13),
+    ("fourteen", 14),
+    ("fifteen", 15),
+    ("sixteen", 16),
+    ("seventeen", 17),
+    ("eighteen", 18),
+    ("nineteen", 19),
+    ("twenty", 20),
+    ("thirty", 30),
+    ("forty", 40),
+    ("fifty", 50),
+    ("sixty", 60),
+    ("seventy", 70),
+    ("eighty", 80),
+    ("ninety", 90),
+];
+
+const GERMAN_WORDS: [(&str, u32); 9] = [
+    ("eins", 1),
+    ("zwei", 2),
+    ("drei", 3),
+    ("vier", 4),
+    ("fünf", 5),
+    ("sechs", 6),
+    ("sieben", 7),
+    ("acht", 8),
+    ("neun", 9),
+];
+
+const GERMAN_TENS: [(&str, u32); 18] = [
+    ("zehn", 10),
+    ("elf", 11),
+    ("zwölf", 12),
+    ("dreizehn", 13),
+    ("vierzehn", 14),
+    ("fünfzehn", 15),
+    ("sechzehn", 16),
+    ("siebzehn", 17),
+    ("achtzehn", 18),
+    ("neunzehn", 19),
+    ("zwanzig", 20),
+    ("dreißig", 30),
+    ("vierzig", 40),
+    ("fünfzig", 50),
+    ("sechzig", 60),
+    ("siebzig", 70),
+    ("achtzig", 80),
+    ("neunzig", 90),
+];
+
+const FRENCH_WORDS: [(&str, u32); 9] = [
+    ("un", 1),
+    ("deux", 2),
+    ("trois", 3),
+    ("quatre", 4),
+    ("cinq", 5),
+    ("six", 6),
+    ("sept", 7),
+    ("huit", 8),
+    ("neuf", 9),
+];
+
+const FRENCH_TENS: [(&str, u32); 18] = [
+    ("dix", 10),
+    ("onze", 11),
+    ("douze", 12),
+    ("treize", 13),
+    ("quatorze", 14),
+    ("quinze", 15),
+    ("seize", 16),
+    ("dix-sept", 17),
+    ("dix-huit", 18),
+    ("dix-neuf", 19),
+    ("vingt", 20),
+    ("trente", 30),
+    ("quarante", 40),
+    ("cinquante", 50),
+    ("soixante", 60),
+    ("soixante-dix", 70),
+    ("quatre-vingt", 80),
+    ("quatre-vingt-dix", 90),
+];
+
+const SPANISH_WORDS: [(&str, u32); 9] = [
+    ("uno", 1),
+    ("dos", 2),
+    ("tres", 3),
+    ("cuatro", 4),
+    ("cinco", 5),
+    ("seis", 6),
+    ("siete", 7),
+    ("ocho", 8),
+    ("nueve", 9),
+];
+
+const SPANISH_TENS: [(&str, u32); 18] = [
+    ("diez", 10),
+    ("once", 11),
+    ("doce", 12),
+    ("trece", 13),
+    ("catorce", 14),
+    ("quince", 15),
+    ("dieciséis", 16),
+    ("diecisiete", 17),
+    ("dieciocho", 18),
+    ("diecinueve", 19),
+    ("veinte", 20),
+    ("treinta", 30),
+    ("cuarenta", 40),
+    ("cincuenta", 50),
+    ("sesenta", 60),
+    ("setenta", 70),
+    ("ochenta", 80),
+    ("noventa", 90),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Vocabulary {
+    English,
+    German,
+    French,
+    Spanish,
+}
+
+impl Vocabulary {
+    pub fn from_name(name: &str) -> Option<Vocabulary> {
+        match name.to_lowercase().as_str() {
+            "english" | "en" => Some(Vocabulary::English),
+            "german" | "de" => Some(Vocabulary::German),
+            "french" | "fr" => Some(Vocabulary::French),
+            "spanish" | "es" => Some(Vocabulary::Spanish),
+            _ => None,
+        }
+    }
+
+    /// The words for one to nine and, if requested, for ten to nineteen and the tens
+    fn words(self: &Vocabulary, with_tens: bool) -> Vec<(&'static str, u32)> {
+        let (words, tens): (WordTable, WordTable) = match self {
+            Vocabulary::English => (&ENGLISH_WORDS, &ENGLISH_TENS),
+            Vocabulary::German => (&GERMAN_WORDS, &GERMAN_TENS),
+            Vocabulary::French => (&FRENCH_WORDS, &FRENCH_TENS),
+            Vocabulary::Spanish => (&SPANISH_WORDS, &SPANISH_TENS),
+        };
+        let mut all_words = words.to_vec();
+        if with_tens {
+            all_words.extend(tens);
+        }
+        all_words
+    }
+}
+
+/// Reads a user supplied word list with one `<word> <value>` per line, e.g. `trzy 3`
+fn read_word_list(filename: &str) -> Result<Vec<(String, u32)>, String> {
+    let content =
+        std::fs::read_to_string(filename).map_err(|_| format!("Could not read {}", filename))?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            let mut line_split = line.split_whitespace();
+            match (
+                line_split.next(),
+                line_split.next().map(|v| v.parse::<u32>()),
+            ) {
+                (Some(word), Some(Ok(value))) => Ok((word.to_string(), value)),
+                _ => Err(format!(
+                    "{} line {}: every word needs a number as value",
+                    filename,
+                    index + 1
+                )),
+            }
+        })
+        .collect()
+}
+
+/// A word (or digit) found in a line, start and length are counted in chars
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Match {
+    start: usize,
+    length: usize,
+    value: u32,
+}
+
+#[derive(Debug, Default)]
+struct Node {
+    next: HashMap<char, usize>,
+    fail: usize,
+    /// Length and value of all words ending in this node, including the ones reached via fail links
+    outputs: Vec<(usize, u32)>,
+}
+
+/// Aho-Corasick automaton over chars, finds all words of a table in a single pass over the text
+#[derive(Debug)]
+struct Automaton {
+    nodes: Vec<Node>,
+}
+
+impl Automaton {
+    fn new(words: &[(Vec<char>, u32)]) -> Automaton {
+        let mut nodes = vec![Node::default()];
+        for (word, value) in words.iter() {
+            let mut node = 0;
+            for c in word.iter() {
+                node = match nodes[node].next.get(c) {
+                    Some(&next) => next,
+                    None => {
+                        nodes.push(Node::default());
+                        let next = nodes.len() - 1;
+                        nodes[node].next.insert(*c, next);
+                        next
+                    }
+                };
+            }
+            nodes[node].outputs.push((word.len(), *value));
+        }
+
+        // Breadth first, so the fail link of a node is always finished before its children
+        let mut queue = nodes[0].next.values().copied().collect::<VecDeque<usize>>();
+        while let Some(node) = queue.pop_front() {
+            let children = nodes[node]
+                .next
+                .iter()
+                .map(|(c, n)| (*c, *n))
+                .collect::<Vec<(char, usize)>>();
+            for (c, child) in children {
+                let mut fail = nodes[node].fail;
+                while fail != 0 && !nodes[fail].next.contains_key(&c) {
+                    fail = nodes[fail].fail;
+                }
+                let child_fail = nodes[fail].next.get(&c).copied().unwrap_or(0);
+                nodes[child].fail = child_fail;
+                let inherited_outputs = nodes[child_fail].outputs.clone();
+                nodes[child].outputs.extend(inherited_outputs);
+                queue.push_back(child);
+            }
+        }
+        Automaton { nodes }
+    }
+
+    fn step(self: &Automaton, mut node: usize, c: char) -> usize {
+        loop {
+            if let Some(&next) = self.nodes[node].next.get(&c) {
+                return next;
+            }
+            if node == 0 {
+                return 0;
+            }
+            node = self.nodes[node].fail;
+        }
+    }
+}
+
+/// Finds the first and last number word or digit of a line. The words are given as a word to value table,
+/// overlapping words like "eightwo" are handled as both words are matched independently.
+#[derive(Debug)]
+pub struct DigitMatcher {
+    forward: Automaton,
+    /// Matches the reversed words on the reversed line, used to find the last word
+    backward: Automaton,
+    max_length: usize,
+}
+
+impl DigitMatcher {
+    pub fn new(words: &[(&str, u32)]) -> DigitMatcher {
+        let forward_words = words
+            .iter()
+            .map(|(w, v)| (w.chars().collect(), *v))
+            .collect::<Vec<(Vec<char>, u32)>>();
+        let backward_words = words
+            .iter()
+            .map(|(w, v)| (w.chars().rev().collect(), *v))
+            .collect::<Vec<(Vec<char>, u32)>>();
+        DigitMatcher {
+            forward: Automaton::new(&forward_words),
+            backward: Automaton::new(&backward_words),
+            max_length: forward_words
+                .iter()
+                .map(|(w, _)| w.len())
+                .max()
+                .unwrap_or(0),
+        }
+    }
+
+    pub fn with_vocabulary(vocabulary: Vocabulary, with_tens: bool) -> DigitMatcher {
+        DigitMatcher::new(&[&DIGITS[..], &vocabulary.words(with_tens)].concat())
+    }
+
+    /// Digits and the words of the file, see read_word_list
+    pub fn with_word_list(filename: &str) -> Result<DigitMatcher, String> {
+        let words = read_word_list(filename)?;
+        let mut table = DIGITS.to_vec();
+        table.extend(words.iter().map(|(w, v)| (w.as_str(), *v)));
+        Ok(DigitMatcher::new(&table))
+    }
+
+    /// Selects the words via `--vocabulary=german` (english, german, french, spanish) or
+    /// `--vocabulary-file=words.txt`, spelled-out teens and tens are added with `--tens`
+    pub fn from_options(options: &Options) -> Result<DigitMatcher, String> {
+        if let Some(filename) = options.get("vocabulary-file") {
+            return DigitMatcher::with_word_list(filename);
+        }
+        let vocabulary = match options.get("vocabulary") {
+            Some(name) => {
+                Vocabulary::from_name(name).ok_or(format!("Unknown vocabulary {}", name))?
+            }
+            None => Vocabulary::English,
+        };
+        Ok(DigitMatcher::with_vocabulary(
+            vocabulary,
+            options.is_set("tens"),
+        ))
+    }
+
+    /// The match starting first, the longest one if several start at the same position
+    fn first(self: &DigitMatcher, line: &str) -> Option<Match> {
+        let mut best: Option<Match> = None;
+        let mut node = 0;
+        for (index, c) in line.chars().enumerate() {
+            // No match ending from here on can start before the best one
+            if best.is_some_and(|b| index >= b.start + self.max_length) {
+                break;
+            }
+            node = self.forward.step(node, c);
+            for &(length, value) in self.forward.nodes[node].outputs.iter() {
+                let start = index + 1 - length;
+                let candidate = Match {
+                    start,
+                    length,
+                    value,
+                };
+                if best.is_none_or(|b| (start, b.length) < (b.start, length)) {
+                    best = Some(candidate);
+                }
+            }
+        }
+        best
+    }
+
+    /// The match starting last, the longest one if several start at the same position
+    fn last(self: &DigitMatcher, line: &str) -> Option<Match> {
+        let char_count = line.chars().count();
+        let mut node = 0;
+        for (index, c) in line.chars().rev().enumerate() {
+            node = self.backward.step(node, c);
+            // The reversed word ending here starts at this position in the line, so the first hit is the last match
+            let longest = self.backward.nodes[node].outputs.iter().max();
+            if let Some(&(length, value)) = longest {
+                return Some(Match {
+                    start: char_count - 1 - index,
+                    length,
+                    value,
+                });
+            }
+        }
+        None
+    }
+}
+
+fn leading_digit(mut value: u32) -> u32 {
+    while value >= 10 {
+        value /= 10;
+    }
+    value
+}
+
+/// A digit or number word found in a line, the start is counted in chars
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    pub text: String,
+    pub start: usize,
+    pub value: u32,
+}
+
+impl Token {
+    fn from_match(line: &str, found: Match) -> Token {
+        Token {
+            text: line.chars().skip(found.start).take(found.length).collect(),
+            start: found.start,
+            value: found.value,
+        }
+    }
+}
+
+/// The calibration value of a line and the tokens it is made of. Lines without any digit or number word have no value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LineCalibration {
+    /// Starts at 1 like in an editor
+    pub line_number: usize,
+    pub first: Option<Token>,
+    pub last: Option<Token>,
+    pub value: Option<u32>,
+}
+
+impl fmt::Display for LineCalibration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.first, &self.last, self.value) {
+            (Some(first), Some(last), Some(value)) => write!(
+                f,
+                "line {}: {} (first \"{}\" at {}, last \"{}\" at {})",
+                self.line_number, value, first.text, first.start, last.text, last.start
+            ),
+            _ => write!(f, "line {}: no digit or number word", self.line_number),
+        }
+    }
+}
+
+/// What to do with lines that have no calibration value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingValues {
+    /// Leaves the lines out of the sum and returns them to be reported
+    Skip,
+    /// Fails with a list of all lines without value
+    Fail,
+}
+
+impl MissingValues {
+    /// `--missing=skip` or `--missing=fail`, the default is to fail
+    pub fn from_options(options: &Options) -> Result<MissingValues, String> {
+        match options.get("missing") {
+            Some("skip") => Ok(MissingValues::Skip),
+            Some("fail") | None => Ok(MissingValues::Fail),
+            Some(other) => Err(format!("Unknown handling of missing values {}", other)),
+        }
+    }
+}
+
+/// Combines the first and last digit or number word to the calibration value. Numbers with
+/// more than one digit, like "twenty", contribute their first or last digit respectively.
+pub fn calibrate_line(line_number: usize, line: &str, matcher: &DigitMatcher) -> LineCalibration {
+    let first = matcher.first(line).map(|m| Token::from_match(line, m));
+    let last = matcher.last(line).map(|m| Token::from_match(line, m));
+    let value = match (&first, &last) {
+        (Some(first), Some(last)) => Some(leading_digit(first.value) * 10 + last.value % 10),
+        _ => None,
+    };
+    LineCalibration {
+        line_number,
+        first,
+        last,
+        value,
+    }
+}
+
+pub fn calibrate(input: &[String], matcher: &DigitMatcher) -> Vec<LineCalibration> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(index, line)| calibrate_line(index + 1, line, matcher))
+        .collect()
+}
+
+/// The sum of the calibration values and the lines skipped because they have no value
+fn sum_calibrations(
+    calibrations: &[LineCalibration],
+    missing: MissingValues,
+) -> Result<(u32, Vec<String>), String> {
+    let missing_lines = calibrations
+        .iter()
+        .filter(|c| c.value.is_none())
+        .map(|c| c.to_string())
+        .collect::<Vec<String>>();
+    if !missing_lines.is_empty() && missing == MissingValues::Fail {
+        return Err(missing_lines.join("\n"));
+    }
+    Ok((
+        calibrations.iter().filter_map(|c| c.value).sum(),
+        missing_lines,
+    ))
+}
+
+/// Sums the calibration values using the digits and number words of the given matcher
+pub fn decode(
+    input: &[String],
+    matcher: &DigitMatcher,
+    missing: MissingValues,
+) -> Result<(u32, Vec<String>), String> {
+    sum_calibrations(&calibrate(input, matcher), missing)
+}
+
+/// The input with the matchers of both parts, which are built once so the parts can be solved on their own
+pub struct Document<'a> {
+    input: &'a [String],
+    digits: DigitMatcher,
+    words: DigitMatcher,
+    missing: MissingValues,
+}
+
+impl<'a> Document<'a> {
+    /// Fails for unknown vocabularies, unreadable word lists and unknown handling of missing values
+    pub fn parse(input: &'a [String], options: &Options) -> Result<Document<'a>, String> {
+        Ok(Document {
+            input,
+            digits: DigitMatcher::new(&DIGITS),
+            words: DigitMatcher::from_options(options)?,
+            missing: MissingValues::from_options(options)?,
+        })
+    }
+
+    /// Only digits count
+    fn sum_digits(self: &Document<'a>) -> Result<(u32, Vec<String>), String> {
+        decode(self.input, &self.digits, self.missing)
+    }
+
+    /// Uses the english words one to nine unless another vocabulary is selected in the options
+    fn sum_words(self: &Document<'a>) -> Result<(u32, Vec<String>), String> {
+        decode(self.input, &self.words, self.missing)
+    }
+}
+
+impl Parts for Document<'_> {
+    fn part_1(&self) -> Result<String, String> {
+        self.sum_digits().map(|(sum, _)| sum.to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        self.sum_words().map(|(sum, _)| sum.to_string())
+    }
+}
+
+/// Both parts are solved on their own, a line without digits fails part 1 without hiding the answer
+/// of part 2. A failed part is answered with `failed`, its problems and the skipped lines are reported.
+/// Fails only if both parts fail or the options are invalid.
+pub fn solve(
+    input: &[String],
+    options: &Options,
+) -> Result<((String, String), Vec<String>), String> {
+    let document = Document::parse(input, options)?;
+    let mut report = vec![];
+    let mut problems = vec![];
+    let mut answer = |part: u32, result: Result<(u32, Vec<String>), String>| match result {
+        Ok((sum, skipped)) => {
+            report.extend(
+                skipped
+                    .iter()
+                    .map(|l| format!("part {} skipped {}", part, l)),
+            );
+            Some(sum.to_string())
+        }
+        Err(message) => {
+            problems.extend(
+                message
+                    .lines()
+                    .map(|l| format!("part {} failed on {}", part, l)),
+            );
+            None
+        }
+    };
+    let answers = (
+        answer(1, document.sum_digits()),
+        answer(2, document.sum_words()),
+    );
+    match answers {
+        (None, None) => Err(problems.join("\n")),
+        (result_1, result_2) => {
+            report.extend(problems);
+            let failed = || "failed".to_string();
+            Ok((
+                (
+                    result_1.unwrap_or_else(failed),
+                    result_2.unwrap_or_else(failed),
+                ),
+                report,
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    extern crate test;
+    use test::Bencher;
+
+    fn part_one(input: &[String], options: &Options) -> Result<(u32, Vec<String>), String> {
+        Document::parse(input, options)?.sum_digits()
+    }
+
+    fn part_two(input: &[String], options: &Options) -> Result<(u32, Vec<String>), String> {
+        Document::parse(input, options)?.sum_words()
+    }
+
+    #[test]
+    fn test_day1_part_1() {
+        let result = 142;
+        let input = vec![
+            "1abc2".to_string(),
+            "pqr3stu8vwx".to_string(),
+            "a1b2c3d4e5f".to_string(),
+            "treb7uchet".to_string(),
+        ];
+        let (output, _) = part_one(&input, &Options::default()).unwrap();
+        assert_eq!(result, output);
+    }
+
+    #[test]
+    fn test_day1_part_2() {
+        let result = 281;
+        let input = vec![
+            "two1nine".to_string(),
+            "eightwothree".to_string(),
+            "abcone2threexyz".to_string(),
+            "xtwone3four".to_string(),
+            "4nineeightseven2".to_string(),
+            "zoneight234".to_string(),
+            "7pqrstsixteen".to_string(),
+        ];
+        let (output, _) = part_two(&input, &Options::default()).unwrap();
+        assert_eq!(result, output);
+
+        // Part 1 fails on the second line, which only has number words
+        assert_eq!(
+            Ok((
+                ("failed".to_string(), "281".to_string()),
+                vec!["part 1 failed on line 2: no digit or number word".to_string()]
+            )),
+            solve(&input, &Options::default())
+        );
+        let (_, options) = Options::parse(&["--missing=skip".to_string()]);
+        assert_eq!(
+            Ok((
+                ("209".to_string(), "281".to_string()),
+                vec!["part 1 skipped line 2: no digit or number word".to_string()]
+            )),
+            solve(&input, &options)
+        );
+    }
+
+    #[test]
+    fn test_day1_overlapping_words() {
+        let matcher = DigitMatcher::with_vocabulary(Vocabulary::English, false);
+        assert_eq!(Some(82), calibrate_line(1, "eightwo", &matcher).value);
+        assert_eq!(Some(18), calibrate_line(1, "xoneightx", &matcher).value);
+        assert_eq!(
+            Some(Match {
+                start: 7,
+                length: 5,
+                value: 3
+            }),
+            matcher.last("abcone2threexyz")
+        );
+
+        // Longer words win over words they contain
+        let teens = DigitMatcher::new(&[("seven", 7), ("seventeen", 17), ("teen", 10)]);
+        assert_eq!(17, teens.first("xseventeen").unwrap().value);
+        assert_eq!(10, teens.last("seventeen").unwrap().value);
+    }
+
+    #[test]
+    fn test_day1_vocabularies() {
+        let input = vec![
+            "zweiundvierzig".to_string(),
+            "xfünfzehnydreißig".to_string(),
+            "acht".to_string(),
+        ];
+        let german = DigitMatcher::with_vocabulary(Vocabulary::German, true);
+        assert_eq!(
+            20 + 10 + 88,
+            decode(&input, &german, MissingValues::Fail).unwrap().0
+        );
+
+        let (_, options) = Options::parse(&["--vocabulary=fr".to_string()]);
+        let french = DigitMatcher::from_options(&options).unwrap();
+        assert_eq!(
+            15 + 31 + 69,
+            decode(
+                &[
+                    "undeuxtroisquatrecinq".to_string(),
+                    "trois1".to_string(),
+                    "sixseptneuf".to_string()
+                ],
+                &french,
+                MissingValues::Fail
+            )
+            .unwrap()
+            .0
+        );
+
+        // Invalid options are errors instead of failed parts
+        let invalid = |arg: &str| {
+            let (_, options) = Options::parse(&[arg.to_string()]);
+            solve(&input, &options).map(|_| ())
+        };
+        assert_eq!(
+            Err("Unknown vocabulary klingon".to_string()),
+            invalid("--vocabulary=klingon")
+        );
+        assert_eq!(
+            Err("Unknown handling of missing values ignore".to_string()),
+            invalid("--missing=ignore")
+        );
+        assert_eq!(
+            Err("Could not read /nonexistent/words.txt".to_string()),
+            invalid("--vocabulary-file=/nonexistent/words.txt")
+        );
+        let filename = std::env::temp_dir().join("advent2023_day1_words.txt");
+        std::fs::write(&filename, "jeden 1\n\ndwa\n").unwrap();
+        let filename = filename.to_string_lossy().to_string();
+        assert_eq!(
+            Err(format!(
+                "{} line 3: every word needs a number as value",
+                filename
+            )),
+            invalid(&format!("--vocabulary-file={}", filename))
+        );
+    }
+
+    #[test]
+    fn test_day1_diagnostics() {
+        let matcher = DigitMatcher::with_vocabulary(Vocabulary::German, false);
+        let input = vec![
+            "äöü3fünfß".to_string(),
+            "keine Zahl".to_string(),
+            "zwei".to_string(),
+        ];
+        let calibrations = calibrate(&input, &matcher);
+        assert_eq!(
+            "line 1: 35 (first \"3\" at 3, last \"fünf\" at 4)",
+            calibrations[0].to_string()
+        );
+        assert_eq!(
+            Err("line 2: no digit or number word".to_string()),
+            sum_calibrations(&calibrations, MissingValues::Fail)
+        );
+        assert_eq!(
+            Ok((35 + 22, vec!["line 2: no digit or number word".to_string()])),
+            sum_calibrations(&calibrations, MissingValues::Skip)
+        );
+    }
+
+    #[bench]
+    fn bench_day1_part_1(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day1.txt");
+        b.iter(|| {
+            part_one(&input, &Options::default()).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_day1_part_2(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day1.txt");
+        b.iter(|| {
+            part_two(&input, &Options::default()).unwrap();
+        });
+    }
+}
@@ -277,7 +277,7 @@ mod tests {
 
     #[bench]
     fn bench_day10_part_1(b: &mut Bencher) {
-        let input = read_input_to_vector("data/day10.txt");
+        let input = read_input_to_vector("data/2023/day10.txt");
         b.iter(|| {
             solve(&input);
         });
@@ -3,6 +3,8 @@ use std::{fmt, ops::Sub};
 
 use num::Integer;
 
+use crate::registry::Parts;
+
 #[derive(Debug, Clone, Copy)]
 struct Position {
     x: i64,
@@ -219,48 +221,79 @@ impl SpaceExploration for AncientGalaxy {
     }
 }
 
-fn solve_part_1(input: &Vec<String>) -> i64 {
-    let mut galaxy = Galaxy {
-        sectors: input.iter().flat_map(|s| s.chars()).collect::<Vec<char>>(),
-        width: input[0].len(),
-        height: input.len(),
-    };
-    galaxy.expand();
-
-    let stars = galaxy.get_stars();
-
-    stars
-        .iter()
-        .combinations(2)
-        .into_iter()
-        .map(|c| {
-            let left = c[0];
-            let right = c[1];
-            galaxy.calculate_distance(left, right)
-        })
-        .sum::<i64>()
+/// The parsed image of the universe, each part expands its own copy
+pub struct Image {
+    sectors: Vec<char>,
+    width: usize,
+    height: usize,
 }
 
-fn solve_part_2(input: &Vec<String>, empty_size: i64) -> i64 {
-    let mut ancient_galaxy = AncientGalaxy {
-        sectors: input.iter().flat_map(|s| s.chars()).collect::<Vec<char>>(),
-        width: input[0].len(),
-        height: input.len(),
-        expansion_size: empty_size,
-    };
+impl Image {
+    pub fn parse(input: &[String]) -> Image {
+        Image {
+            sectors: input.iter().flat_map(|s| s.chars()).collect::<Vec<char>>(),
+            width: input.first().map_or(0, |row| row.len()),
+            height: input.len(),
+        }
+    }
+
+    fn distances(self: &Image) -> i64 {
+        let mut galaxy = Galaxy {
+            sectors: self.sectors.clone(),
+            width: self.width,
+            height: self.height,
+        };
+        galaxy.expand();
+
+        let stars = galaxy.get_stars();
+
+        stars
+            .iter()
+            .combinations(2)
+            .map(|c| {
+                let left = c[0];
+                let right = c[1];
+                galaxy.calculate_distance(left, right)
+            })
+            .sum::<i64>()
+    }
 
-    ancient_galaxy.expand();
+    fn ancient_distances(self: &Image, empty_size: i64) -> i64 {
+        let mut ancient_galaxy = AncientGalaxy {
+            sectors: self.sectors.clone(),
+            width: self.width,
+            height: self.height,
+            expansion_size: empty_size,
+        };
 
-    let stars = ancient_galaxy.get_stars();
+        ancient_galaxy.expand();
 
-    let distances = stars
-        .iter()
-        .combinations(2)
-        .into_iter()
-        .map(|c| ancient_galaxy.calculate_distance(c[0], c[1]))
-        .sum::<i64>();
+        let stars = ancient_galaxy.get_stars();
 
-    distances
+        stars
+            .iter()
+            .combinations(2)
+            .map(|c| ancient_galaxy.calculate_distance(c[0], c[1]))
+            .sum::<i64>()
+    }
+}
+
+impl Parts for Image {
+    fn part_1(&self) -> Result<String, String> {
+        Ok(self.distances().to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        Ok(self.ancient_distances(1000000).to_string())
+    }
+}
+
+fn solve_part_1(input: &Vec<String>) -> i64 {
+    Image::parse(input).distances()
+}
+
+fn solve_part_2(input: &Vec<String>, empty_size: i64) -> i64 {
+    Image::parse(input).ancient_distances(empty_size)
 }
 
 pub fn solve(input: &Vec<String>) -> (i64, i64) {
@@ -338,7 +371,7 @@ mod tests {
 
     #[bench]
     fn bench_day11_part_1(b: &mut Bencher) {
-        let input = read_input_to_vector("data/day11.txt");
+        let input = read_input_to_vector("data/2023/day11.txt");
         b.iter(|| {
             solve_part_1(&input);
         });
@@ -346,7 +379,7 @@ mod tests {
 
     #[bench]
     fn bench_day11_part_2(b: &mut Bencher) {
-        let input = read_input_to_vector("data/day11.txt");
+        let input = read_input_to_vector("data/2023/day11.txt");
         b.iter(|| {
             solve_part_2(&input, 1000000);
         });
@@ -1,3 +1,5 @@
+use crate::registry::Parts;
+
 #[derive(Debug, Clone)]
 struct SpringConfig {
     springs: Vec<char>,
@@ -96,38 +98,57 @@ impl SpringConfig {
     }
 }
 
+/// The parsed condition records
+pub struct Records {
+    configs: Vec<SpringConfig>,
+}
+
+impl Records {
+    pub fn parse(input: &[String]) -> Records {
+        let configs = input
+            .iter()
+            .map(|s| {
+                let split = s.split(" ").collect::<Vec<&str>>();
+                let record = split[0].chars().collect::<Vec<char>>();
+                let config = split[1]
+                    .split(",")
+                    .map(|s| s.parse::<u32>().unwrap())
+                    .collect::<Vec<u32>>();
+                SpringConfig {
+                    springs: record,
+                    damaged_groups: config,
+                }
+            })
+            .collect::<Vec<SpringConfig>>();
+        Records { configs }
+    }
+
+    fn variations(self: &Records) -> i64 {
+        self.configs.iter().map(|c| c.find_variations()).sum()
+    }
+
+    fn unfold_variations(self: &Records) -> i64 {
+        self.configs
+            .iter()
+            .map(|c| c.find_unfold_variations(5))
+            .sum()
+    }
+}
+
+impl Parts for Records {
+    fn part_1(&self) -> Result<String, String> {
+        Ok(self.variations().to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        Ok(self.unfold_variations().to_string())
+    }
+}
+
 pub fn solve(input: &Vec<String>) -> (i64, i64) {
-    let configs = input
-        .iter()
-        .map(|s| {
-            let split = s.split(" ").collect::<Vec<&str>>();
-            let record = split[0].chars().collect::<Vec<char>>();
-            let config = split[1]
-                .split(",")
-                .map(|s| s.parse::<u32>().unwrap())
-                .collect::<Vec<u32>>();
-            SpringConfig {
-                springs: record,
-                damaged_groups: config,
-            }
-        })
-        .collect::<Vec<SpringConfig>>();
-
-    let res = configs
-        .iter()
-        .map(|c| c.find_variations())
-        .collect::<Vec<i64>>()
-        .iter()
-        .sum();
-
-    let res_fold = configs
-        .iter()
-        .map(|c| c.find_unfold_variations(5))
-        .collect::<Vec<i64>>()
-        .iter()
-        .sum();
-
-    (res, res_fold)
+    let records = Records::parse(input);
+
+    (records.variations(), records.unfold_variations())
 }
 
 #[cfg(test)]
@@ -156,7 +177,7 @@ mod tests {
 
     #[bench]
     fn bench_day12_part_1(b: &mut Bencher) {
-        let input = read_input_to_vector("data/day12.txt");
+        let input = read_input_to_vector("data/2023/day12.txt");
         b.iter(|| {
             solve(&input);
         });
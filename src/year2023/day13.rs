@@ -0,0 +1,411 @@
+use std::fmt;
+
+use crate::registry::Parts;
+use crate::repl::Explore;
+
+#[derive(Debug, Clone)]
+struct Field {
+    fields: Vec<char>,
+    width: usize,
+    height: usize,
+}
+
+impl Field {
+    fn get(&self, x: usize, y: usize) -> char {
+        self.fields[y * self.width + x]
+    }
+
+    fn check_row_symmetry(&self, start_row: usize) -> bool {
+        let upper_half = (0..(start_row + 1)).rev();
+        let lower_half = (start_row + 1)..self.height;
+        for (upper, lower) in upper_half.into_iter().zip(lower_half.into_iter()) {
+            for column_index in 0..self.width {
+                if self.get(column_index, upper) != self.get(column_index, lower) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn check_row_symmetry_with_smudge(&self, start_row: usize) -> bool {
+        let upper_half = (0..(start_row + 1)).rev();
+        let lower_half = (start_row + 1)..self.height;
+        let mut number_of_smudges = 0;
+
+        for (upper, lower) in upper_half.into_iter().zip(lower_half.into_iter()) {
+            let mut smudge = false;
+
+            for column_index in 0..self.width {
+                if self.get(column_index, upper) != self.get(column_index, lower) {
+                    if smudge {
+                        return false;
+                    } else {
+                        smudge = true;
+                        number_of_smudges += 1;
+                    }
+                }
+            }
+        }
+
+        number_of_smudges == 1
+    }
+
+    fn find_row_symmetry(&self, tolerate_smudge: bool) -> Option<usize> {
+        let symmetry_check = if tolerate_smudge {
+            Field::check_row_symmetry_with_smudge
+        } else {
+            Field::check_row_symmetry
+        };
+        'row_loop: for row_index in 0..self.height - 1 {
+            let mut smudge = false;
+            for column_index in 0..self.width {
+                if self.get(column_index, row_index) != self.get(column_index, row_index + 1) {
+                    if tolerate_smudge {
+                        if smudge {
+                            continue 'row_loop;
+                        } else {
+                            smudge = true;
+                        }
+                    } else {
+                        continue 'row_loop;
+                    }
+                }
+            }
+            if symmetry_check(self, row_index) {
+                return Some(row_index);
+            }
+        }
+        None
+    }
+
+    fn check_column_symmetry(&self, start_column: usize) -> bool {
+        let left_half = (0..(start_column + 1)).rev();
+        let right_half = (start_column + 1)..self.width;
+        for (left, right) in left_half.into_iter().zip(right_half.into_iter()) {
+            for row_index in 0..self.height {
+                if self.get(left, row_index) != self.get(right, row_index) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+
+    fn check_column_symmetry_with_smudge(&self, start_column: usize) -> bool {
+        let left_half = (0..(start_column + 1)).rev();
+        let right_half = (start_column + 1)..self.width;
+        let mut number_of_smudges = 0;
+        for (left, right) in left_half.into_iter().zip(right_half.into_iter()) {
+            let mut smudge = false;
+            for row_index in 0..self.height {
+                if self.get(left, row_index) != self.get(right, row_index) {
+                    if smudge {
+                        return false;
+                    } else {
+                        smudge = true;
+                        number_of_smudges += 1;
+                    }
+                }
+            }
+        }
+
+        number_of_smudges == 1
+    }
+
+    fn find_column_symmetry(&self, tolerate_smudge: bool) -> Option<usize> {
+        let symmetry_check = if tolerate_smudge {
+            Field::check_column_symmetry_with_smudge
+        } else {
+            Field::check_column_symmetry
+        };
+        'column_loop: for column_index in 0..self.width - 1 {
+            let mut smudge = false;
+            for row_index in 0..self.height {
+                if self.get(column_index, row_index) != self.get(column_index + 1, row_index) {
+                    if tolerate_smudge {
+                        if smudge {
+                            continue 'column_loop;
+                        } else {
+                            smudge = true;
+                        }
+                    } else {
+                        continue 'column_loop;
+                    }
+                }
+            }
+            if symmetry_check(self, column_index) {
+                return Some(column_index);
+            }
+        }
+        None
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row_index in 0..self.height {
+            for column_index in 0..self.width {
+                write!(f, "{}", self.get(column_index, row_index))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Field {
+    /// Shows the field with the line of reflection marked like in the puzzle description:
+    /// `v` and `^` in front of the two mirrored rows or `>` and `<` above the two mirrored columns
+    fn display_symmetry(&self, tolerate_smudge: bool) -> String {
+        let row_symmetry = self.find_row_symmetry(tolerate_smudge);
+        let column_symmetry = match row_symmetry {
+            Some(_) => None,
+            None => self.find_column_symmetry(tolerate_smudge),
+        };
+
+        let mut output = String::new();
+        if let Some(column_index) = column_symmetry {
+            output += &format!(" {}><\n", " ".repeat(column_index));
+        }
+        for (row_index, row) in self.to_string().lines().enumerate() {
+            let marker = match row_symmetry {
+                Some(r) if r == row_index => 'v',
+                Some(r) if r + 1 == row_index => '^',
+                _ => ' ',
+            };
+            output += &format!("{}{}\n", marker, row);
+        }
+        output += &match (row_symmetry, column_symmetry) {
+            (Some(r), _) => format!("reflection between rows {} and {}", r + 1, r + 2),
+            (_, Some(c)) => format!("reflection between columns {} and {}", c + 1, c + 2),
+            _ => "no reflection".to_string(),
+        };
+        output
+    }
+}
+
+/// All parsed fields of the input, which can be explored in the REPL
+pub struct Valley {
+    fields: Vec<Field>,
+}
+
+impl Valley {
+    pub fn parse(input: &[String]) -> Result<Valley, String> {
+        if input.iter().all(|row| row.is_empty()) {
+            return Err("There are no fields".to_string());
+        }
+        Ok(Valley {
+            fields: fields_from_input(input)?,
+        })
+    }
+}
+
+impl Explore for Valley {
+    fn help(self: &Valley) -> Vec<&'static str> {
+        vec![
+            "fields                     shows the number of fields",
+            "field <n> [smudge]         shows field n (starting at 1) with its line of reflection",
+        ]
+    }
+
+    fn query(self: &Valley, command: &str, arguments: &[&str]) -> Option<String> {
+        let output = match command {
+            "fields" => format!("{} fields", self.fields.len()),
+            "field" => {
+                let field = arguments
+                    .first()
+                    .and_then(|n| n.parse::<usize>().ok())
+                    .and_then(|n| self.fields.get(n.wrapping_sub(1)));
+                match field {
+                    Some(field) => field.display_symmetry(arguments.get(1) == Some(&"smudge")),
+                    None => format!("Please specify a field from 1 to {}", self.fields.len()),
+                }
+            }
+            _ => return None,
+        };
+        Some(output)
+    }
+}
+
+/// The fields are separated by a blank line, a field needs at least one row and all its rows need
+/// the same width
+fn fields_from_input(input: &[String]) -> Result<Vec<Field>, String> {
+    let mut fields = vec![];
+    let mut errors = vec![];
+    let empty_field = Field {
+        fields: vec![],
+        width: 0,
+        height: 0,
+    };
+    let mut field = empty_field.clone();
+
+    for (line_number, row) in input.iter().enumerate() {
+        if row.is_empty() {
+            if field.height == 0 {
+                errors.push(format!("line {}: empty field", line_number + 1));
+            } else {
+                fields.push(field);
+            }
+            field = empty_field.clone();
+        } else if field.height > 0 && row.len() != field.width {
+            errors.push(format!(
+                "line {}: {} columns instead of {}",
+                line_number + 1,
+                row.len(),
+                field.width
+            ));
+        } else {
+            field.width = row.len();
+            field.fields.extend(row.chars());
+            field.height += 1;
+        }
+    }
+    if field.height == 0 {
+        errors.push(format!("line {}: empty field", input.len() + 1));
+    } else {
+        fields.push(field);
+    }
+
+    if errors.is_empty() {
+        Ok(fields)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+impl Valley {
+    fn reflection_sum(self: &Valley) -> i64 {
+        let mut reflection_sum = 0;
+        self.fields.iter().for_each(|field| {
+            if let Some(row_index) = field.find_row_symmetry(false) {
+                reflection_sum += 100 * (row_index + 1) as i64;
+            } else if let Some(column_index) = field.find_column_symmetry(false) {
+                reflection_sum += (column_index + 1) as i64;
+            }
+        });
+        reflection_sum
+    }
+
+    /// Fails if a field has no reflection with exactly one smudge
+    fn reflection_sum_with_smudge(self: &Valley) -> Result<i64, String> {
+        let mut reflection_sum_with_smudge = 0;
+        for (index, field) in self.fields.iter().enumerate() {
+            if let Some(row_index) = field.find_row_symmetry(true) {
+                reflection_sum_with_smudge += 100 * (row_index + 1) as i64;
+            } else if let Some(column_index) = field.find_column_symmetry(true) {
+                reflection_sum_with_smudge += (column_index + 1) as i64;
+            } else {
+                return Err(format!(
+                    "Field {} has no reflection with a smudge",
+                    index + 1
+                ));
+            }
+        }
+        Ok(reflection_sum_with_smudge)
+    }
+}
+
+impl Parts for Valley {
+    fn part_1(&self) -> Result<String, String> {
+        Ok(self.reflection_sum().to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        self.reflection_sum_with_smudge().map(|sum| sum.to_string())
+    }
+}
+
+pub fn solve(input: &[String]) -> Result<(i64, i64), String> {
+    let valley = Valley::parse(input)?;
+
+    Ok((
+        valley.reflection_sum(),
+        valley.reflection_sum_with_smudge()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    extern crate test;
+    use test::Bencher;
+
+    #[test]
+    fn test_day13() {
+        let result_1 = 405;
+        let result_2 = 400;
+        let input = vec![
+            "#.##..##.".to_string(),
+            "..#.##.#.".to_string(),
+            "##......#".to_string(),
+            "##......#".to_string(),
+            "..#.##.#.".to_string(),
+            "..##..##.".to_string(),
+            "#.#.##.#.".to_string(),
+            "".to_string(),
+            "#...##..#".to_string(),
+            "#....#..#".to_string(),
+            "..##..###".to_string(),
+            "#####.##.".to_string(),
+            "#####.##.".to_string(),
+            "..##..###".to_string(),
+            "#....#..#".to_string(),
+        ];
+        let (output_1, output_2) = solve(&input).unwrap();
+        assert_eq!(result_1, output_1);
+        assert_eq!(result_2, output_2);
+
+        let valley = Valley::parse(&input).unwrap();
+        assert_eq!(
+            Some(
+                [
+                    "     ><",
+                    " #.##..##.",
+                    " ..#.##.#.",
+                    " ##......#",
+                    " ##......#",
+                    " ..#.##.#.",
+                    " ..##..##.",
+                    " #.#.##.#.",
+                    "reflection between columns 5 and 6",
+                ]
+                .join("\n")
+            ),
+            valley.query("field", &["1"])
+        );
+    }
+
+    #[test]
+    fn test_day13_validation() {
+        let input = ["#.#", "", "", "##.", "#.", "", "..#", ""].map(|l| l.to_string());
+        assert_eq!(
+            Err([
+                "line 3: empty field",
+                "line 5: 2 columns instead of 3",
+                "line 9: empty field",
+            ]
+            .join("\n")),
+            Valley::parse(&input).map(|_| ())
+        );
+        assert_eq!(
+            Err("There are no fields".to_string()),
+            Valley::parse(&["".to_string()]).map(|_| ())
+        );
+        // Without a reflection part 1 counts nothing, part 2 cannot be answered
+        let input = vec!["#.".to_string(), ".#".to_string()];
+        assert_eq!(
+            Err("Field 1 has no reflection with a smudge".to_string()),
+            solve(&input)
+        );
+    }
+
+    #[bench]
+    fn bench_day13_part_1(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day13.txt");
+        b.iter(|| {
+            solve(&input).unwrap();
+        });
+    }
+}
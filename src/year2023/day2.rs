@@ -0,0 +1,658 @@
+use std::cmp::max;
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::my_io::read_input_to_vector;
+use crate::options::Options;
+use crate::registry::Parts;
+
+/// Number of cubes per color in the order of the input, colors not in the list count as 0
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Draw {
+    counts: Vec<(String, u32)>,
+}
+
+impl Draw {
+    fn get(self: &Draw, color: &str) -> u32 {
+        self.counts
+            .iter()
+            .find(|(c, _)| c == color)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// The count of a color, added at the end if the color is new
+    fn entry(self: &mut Draw, color: &str) -> &mut u32 {
+        let index = match self.counts.iter().position(|(c, _)| c == color) {
+            Some(index) => index,
+            None => {
+                self.counts.push((color.to_string(), 0));
+                self.counts.len() - 1
+            }
+        };
+        &mut self.counts[index].1
+    }
+
+    /// A draw fits into a bag if the bag has at least as many cubes of every drawn color
+    fn fits_into(self: &Draw, bag: &Draw) -> bool {
+        self.counts
+            .iter()
+            .all(|(color, count)| *count <= bag.get(color))
+    }
+
+    /// Product of the counts of the colors, a color that was not drawn counts as 0
+    fn power(self: &Draw, colors: &[String]) -> u32 {
+        colors.iter().map(|color| self.get(color)).product()
+    }
+}
+
+/// Formats like the input, e.g. `3 blue, 4 red`
+impl fmt::Display for Draw {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cubes = self
+            .counts
+            .iter()
+            .map(|(color, count)| format!("{} {}", count, color))
+            .collect::<Vec<String>>();
+        write!(f, "{}", cubes.join(", "))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Game {
+    id: u32,
+    draws: Vec<Draw>,
+}
+
+impl Game {
+    /// The highest count of every color over all draws of the game
+    fn max_draws(self: &Game) -> Draw {
+        let mut draw_max = Draw {
+            ..Default::default()
+        };
+        for d in self.draws.iter() {
+            for (color, count) in d.counts.iter() {
+                let entry = draw_max.entry(color);
+                *entry = max(*entry, *count);
+            }
+        }
+        draw_max
+    }
+}
+
+/// Formats like the input line, e.g. `Game 1: 3 blue, 4 red; 2 green`
+impl fmt::Display for Game {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let draws = self
+            .draws
+            .iter()
+            .map(|d| d.to_string())
+            .collect::<Vec<String>>();
+        write!(f, "Game {}: {}", self.id, draws.join("; "))
+    }
+}
+
+fn parse_draw(draw_line: &str) -> Draw {
+    let mut draw = Draw {
+        ..Default::default()
+    };
+
+    for color_line in draw_line.split(",") {
+        let mut color_split = color_line.trim().split(" ");
+        let counter = color_split
+            .next()
+            .expect("Second value should be a number")
+            .parse::<u32>()
+            .expect("Second value should be a number");
+        let color = color_split.next().expect("First value should be a color");
+        *draw.entry(color) += counter;
+    }
+
+    draw
+}
+
+fn parse_game(input_line: &str) -> Game {
+    let mut game_split = input_line.split(":");
+    let game_id = game_split
+        .next()
+        .expect("There was no : in the line")
+        .split(" ")
+        .filter_map(|x| x.parse::<u32>().ok())
+        .next()
+        .expect("Cannot parse game id");
+
+    let mut game = Game {
+        id: game_id,
+        draws: vec![],
+    };
+    for draw_line in game_split
+        .next()
+        .expect("There was no : in the line")
+        .split(";")
+    {
+        game.draws.push(parse_draw(draw_line))
+    }
+    game
+}
+
+/// The bag of the puzzle, used if no bags are given in the options
+const DEFAULT_BAG: &str = "12 red, 13 green, 14 blue";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Bag {
+    name: String,
+    contents: Draw,
+}
+
+/// A color of a draw with more cubes than the bag contains
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Violation {
+    bag: String,
+    game: u32,
+    /// Starts at 1
+    draw: usize,
+    color: String,
+    count: u32,
+    limit: u32,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: game {} draw {} has {} {}, but the bag only has {}",
+            self.bag, self.game, self.draw, self.count, self.color, self.limit
+        )
+    }
+}
+
+/// A bag is given like a draw, optionally with a name: `big bag: 20 red, 20 green, 20 blue`
+fn parse_bag(bag_line: &str, index: usize) -> Bag {
+    match bag_line.split_once(":") {
+        Some((name, contents)) => Bag {
+            name: name.trim().to_string(),
+            contents: parse_draw(contents),
+        },
+        None => Bag {
+            name: format!("bag {}", index + 1),
+            contents: parse_draw(bag_line),
+        },
+    }
+}
+
+/// Bags are given as `--bag="12 red, 13 green; 20 red, 20 green"` (several bags separated by ;)
+/// or in a file `--bags-file=bags.txt` with one bag per line
+fn bags_from_options(options: &Options) -> Vec<Bag> {
+    let bag_lines = if let Some(filename) = options.get("bags-file") {
+        read_input_to_vector(filename)
+            .into_iter()
+            .filter(|l| !l.trim().is_empty())
+            .collect()
+    } else {
+        options
+            .get("bag")
+            .unwrap_or(DEFAULT_BAG)
+            .split(";")
+            .map(|l| l.to_string())
+            .collect::<Vec<String>>()
+    };
+    bag_lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| parse_bag(line, index))
+        .collect()
+}
+
+impl Game {
+    fn violations(self: &Game, bag: &Bag) -> Vec<Violation> {
+        let mut violations = vec![];
+        for (index, draw) in self.draws.iter().enumerate() {
+            for (color, count) in draw.counts.iter() {
+                let limit = bag.contents.get(color);
+                if *count > limit {
+                    violations.push(Violation {
+                        bag: bag.name.clone(),
+                        game: self.id,
+                        draw: index + 1,
+                        color: color.clone(),
+                        count: *count,
+                        limit,
+                    });
+                }
+            }
+        }
+        violations
+    }
+}
+
+/// Evaluates all bags in one pass over the games and returns the id sum of the possible games per bag
+/// and the draws that are not possible with a bag
+fn evaluate(games: &[Game], bags: &[Bag]) -> (Vec<u32>, Vec<Violation>) {
+    let mut id_sums = vec![0; bags.len()];
+    let mut violations = vec![];
+    for game in games {
+        let max_draws = game.max_draws();
+        for (bag, id_sum) in bags.iter().zip(id_sums.iter_mut()) {
+            if max_draws.fits_into(&bag.contents) {
+                *id_sum += game.id;
+            } else {
+                violations.extend(game.violations(bag));
+            }
+        }
+    }
+
+    (id_sums, violations)
+}
+
+/// The colors of all games in the order they first appear
+fn all_colors(games: &[Game]) -> Vec<String> {
+    let mut colors = Draw::default();
+    for draw in games.iter().flat_map(|g| g.draws.iter()) {
+        for (color, _) in draw.counts.iter() {
+            colors.entry(color);
+        }
+    }
+    colors.counts.into_iter().map(|(color, _)| color).collect()
+}
+
+/// The power of a game multiplies the fewest cubes of every color of the input, so a game that
+/// never shows one of the colors has power 0
+fn power_sum(games: &[Game]) -> u32 {
+    let colors = all_colors(games);
+    games.iter().map(|g| g.max_draws().power(&colors)).sum()
+}
+
+/// A single bag gives just its id sum, several bags are listed with their names
+fn format_id_sums(bags: &[Bag], id_sums: &[u32]) -> String {
+    if bags.len() == 1 {
+        id_sums[0].to_string()
+    } else {
+        bags.iter()
+            .zip(id_sums.iter())
+            .map(|(bag, id_sum)| format!("{}: {}", bag.name, id_sum))
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+}
+
+/// The parsed games and the bags to check them against
+pub struct Record {
+    games: Vec<Game>,
+    bags: Vec<Bag>,
+}
+
+impl Record {
+    pub fn parse(input_lines: &[String], options: &Options) -> Record {
+        Record {
+            games: input_lines.iter().map(|l| parse_game(l)).collect(),
+            bags: bags_from_options(options),
+        }
+    }
+}
+
+impl Parts for Record {
+    fn part_1(&self) -> Result<String, String> {
+        let (id_sums, _) = evaluate(&self.games, &self.bags);
+        Ok(format_id_sums(&self.bags, &id_sums))
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        Ok(power_sum(&self.games).to_string())
+    }
+}
+
+/// Largest number of cubes per color considered by the estimation, if not given with `--max-cubes`
+const DEFAULT_MAX_CUBES: u32 = 100;
+
+/// 2 times the log-likelihood difference at the edge of the 95% confidence interval (chi-square with one degree of freedom)
+const CONFIDENCE_95: f64 = 3.841;
+
+/// Estimated number of cubes of one color in the bag with its 95% confidence interval
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Estimate {
+    color: String,
+    count: u32,
+    lower: u32,
+    upper: u32,
+    /// The confidence interval reaches the largest count searched, the bag may contain even more cubes
+    at_limit: bool,
+}
+
+impl fmt::Display for Estimate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} (95% confidence interval {}..{}){}",
+            self.color,
+            self.count,
+            self.lower,
+            self.upper,
+            if self.at_limit {
+                " at the search limit"
+            } else {
+                ""
+            }
+        )
+    }
+}
+
+/// ln(n!) for all n up to the size of the largest possible bag
+struct LogFactorials {
+    values: Vec<f64>,
+}
+
+impl LogFactorials {
+    fn new(max: u32) -> LogFactorials {
+        let mut values = vec![0.0];
+        for n in 1..=max {
+            values.push(values[n as usize - 1] + (n as f64).ln());
+        }
+        LogFactorials { values }
+    }
+
+    fn ln_choose(self: &LogFactorials, n: u32, k: u32) -> f64 {
+        self.values[n as usize] - self.values[k as usize] - self.values[(n - k) as usize]
+    }
+}
+
+/// Every draw takes its cubes without replacement from the bag, so the colors of a draw follow the
+/// multivariate hypergeometric distribution: P(draw) = prod_c C(N_c, k_c) / C(N, n)
+fn log_likelihood(draws: &[Vec<u32>], counts: &[u32], log_factorials: &LogFactorials) -> f64 {
+    let total = counts.iter().sum::<u32>();
+    draws
+        .iter()
+        .map(|draw| {
+            let drawn = draw.iter().sum::<u32>();
+            let colors = counts
+                .iter()
+                .zip(draw.iter())
+                .map(|(n, k)| log_factorials.ln_choose(*n, *k))
+                .sum::<f64>();
+            colors - log_factorials.ln_choose(total, drawn)
+        })
+        .sum()
+}
+
+/// Maximum likelihood estimate of the bag contents over all draws of all games. The counts are
+/// optimized one color at a time until nothing changes, starting from the highest count seen per color.
+/// The confidence interval of a color is the likelihood-ratio interval with the other colors at their estimate.
+fn estimate_bag(games: &[Game], max_cubes: u32) -> Vec<Estimate> {
+    let colors = games
+        .iter()
+        .flat_map(|g| g.max_draws().counts.into_iter().map(|(color, _)| color))
+        .collect::<BTreeSet<String>>()
+        .into_iter()
+        .collect::<Vec<String>>();
+    let draws = games
+        .iter()
+        .flat_map(|g| g.draws.iter())
+        .map(|d| colors.iter().map(|c| d.get(c)).collect::<Vec<u32>>())
+        .collect::<Vec<Vec<u32>>>();
+    let minimum_counts = (0..colors.len())
+        .map(|c| draws.iter().map(|d| d[c]).max().unwrap_or(0))
+        .collect::<Vec<u32>>();
+    // Colors seen more often than the limit are only searched at their observed count
+    let search_limits = minimum_counts
+        .iter()
+        .map(|minimum| max_cubes.max(*minimum))
+        .collect::<Vec<u32>>();
+    let log_factorials = LogFactorials::new(search_limits.iter().sum());
+
+    let likelihoods_of_color = |counts: &Vec<u32>, color: usize| -> Vec<(u32, f64)> {
+        let mut candidate = counts.clone();
+        (minimum_counts[color]..=search_limits[color])
+            .map(|n| {
+                candidate[color] = n;
+                (n, log_likelihood(&draws, &candidate, &log_factorials))
+            })
+            .collect()
+    };
+
+    let mut counts = minimum_counts.clone();
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for color in 0..colors.len() {
+            let (best, _) = likelihoods_of_color(&counts, color).into_iter().fold(
+                (counts[color], f64::NEG_INFINITY),
+                |best, candidate| {
+                    if candidate.1 > best.1 {
+                        candidate
+                    } else {
+                        best
+                    }
+                },
+            );
+            if best != counts[color] {
+                counts[color] = best;
+                changed = true;
+            }
+        }
+    }
+
+    let maximum = log_likelihood(&draws, &counts, &log_factorials);
+    colors
+        .iter()
+        .enumerate()
+        .map(|(color, name)| {
+            let inside = likelihoods_of_color(&counts, color)
+                .into_iter()
+                .filter(|(_, l)| 2.0 * (maximum - l) <= CONFIDENCE_95)
+                .map(|(n, _)| n)
+                .collect::<Vec<u32>>();
+            let upper = *inside.last().unwrap_or(&counts[color]);
+            Estimate {
+                color: name.clone(),
+                count: counts[color],
+                lower: *inside.first().unwrap_or(&counts[color]),
+                upper,
+                at_limit: upper >= search_limits[color],
+            }
+        })
+        .collect()
+}
+
+/// With several bags the first answer lists the id sum of every bag.
+/// The draws that violate a bag are reported with `--violations`, an estimate of the bag contents with `--estimate`.
+/// Fails if `--max-cubes` is not a number.
+pub fn solve(
+    input_lines: &[String],
+    options: &Options,
+) -> Result<((String, u32), Vec<String>), String> {
+    let record = Record::parse(input_lines, options);
+    let (id_sums, violations) = evaluate(&record.games, &record.bags);
+
+    let mut report = vec![];
+    if options.is_set("violations") {
+        report.extend(violations.iter().map(|v| v.to_string()));
+    }
+    if options.is_set("estimate") {
+        let max_cubes = match options.get("max-cubes") {
+            Some(m) => m
+                .parse()
+                .map_err(|_| format!("Maximum number of cubes must be a number: {}", m))?,
+            None => DEFAULT_MAX_CUBES,
+        };
+        report.extend(
+            estimate_bag(&record.games, max_cubes)
+                .iter()
+                .map(|e| e.to_string()),
+        );
+    }
+
+    Ok((
+        (
+            format_id_sums(&record.bags, &id_sums),
+            power_sum(&record.games),
+        ),
+        report,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    use crate::testing::check_round_trip;
+    extern crate test;
+    use test::Bencher;
+
+    #[test]
+    fn test_day2() {
+        let result_1 = 8;
+        let result_2 = 2286;
+        let input = vec![
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green".to_string(),
+            "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue".to_string(),
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red".to_string(),
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red".to_string(),
+            "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green".to_string(),
+        ];
+        let ((output_1, output_2), report) = solve(&input, &Options::default()).unwrap();
+        assert_eq!(result_1.to_string(), output_1);
+        assert_eq!(result_2, output_2);
+        assert!(report.is_empty());
+
+        let (_, options) = Options::parse(&[
+            "--bag=12 red, 13 green, 14 blue; small: 4 red, 3 green, 6 blue".to_string(),
+        ]);
+        let record = Record::parse(&input, &options);
+        let (id_sums, violations) = evaluate(&record.games, &record.bags);
+        assert_eq!(vec![8, 3], id_sums);
+        assert_eq!(
+            "bag 1: game 3 draw 1 has 20 red, but the bag only has 12",
+            violations[0].to_string()
+        );
+        assert_eq!(
+            "small: game 5 draw 1 has 6 red, but the bag only has 4",
+            violations.last().unwrap().to_string()
+        );
+        assert_eq!(
+            ("bag 1: 8, small: 3".to_string(), result_2),
+            solve(&input, &options).unwrap().0
+        );
+
+        let (_, options) = Options::parse(&[
+            "--bag=small: 4 red, 3 green, 6 blue".to_string(),
+            "--violations".to_string(),
+        ]);
+        let (_, report) = solve(&input, &options).unwrap();
+        assert_eq!(
+            vec![
+                "small: game 3 draw 1 has 8 green, but the bag only has 3",
+                "small: game 3 draw 1 has 20 red, but the bag only has 4",
+                "small: game 3 draw 2 has 13 green, but the bag only has 3",
+                "small: game 3 draw 3 has 5 green, but the bag only has 3",
+            ],
+            report[..4]
+        );
+    }
+
+    #[test]
+    fn test_day2_any_colors() {
+        let game = parse_game("Game 7: 3 yellow, 1 red; 2 purple, 4 yellow; 1 red, 1 red");
+        let max_draws = game.max_draws();
+        assert_eq!(4, max_draws.get("yellow"));
+        assert_eq!(2, max_draws.get("red"));
+        assert_eq!(0, max_draws.get("blue"));
+        assert_eq!(
+            4 * 2 * 2,
+            max_draws.power(&["yellow", "red", "purple"].map(|c| c.to_string()))
+        );
+        // A color of another game is missing in this one, so only the other game has power
+        let games = [
+            game.clone(),
+            parse_game("Game 8: 1 blue, 2 red, 3 yellow, 4 purple"),
+        ];
+        assert_eq!(vec!["yellow", "red", "purple", "blue"], all_colors(&games));
+        assert_eq!(2 * 3 * 4, power_sum(&games));
+        assert!(!max_draws.fits_into(&parse_draw("12 red, 13 yellow")));
+        assert!(max_draws.fits_into(&parse_draw("2 red, 4 yellow, 2 purple")));
+    }
+
+    #[test]
+    fn test_day2_round_trip() {
+        let lines = [
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            "Game 7: 3 yellow, 1 red; 2 purple, 4 yellow",
+            "Game 42: 0 green",
+            "Game 100: 20 red, 13 green, 14 blue, 1 purple, 5 yellow; 1 blue; 2 red",
+        ];
+        for line in lines {
+            let game = parse_game(line);
+            assert_eq!(line, game.to_string());
+            assert_eq!(game, parse_game(&game.to_string()));
+        }
+
+        let colors = ["red", "green", "blue", "yellow", "purple"];
+        check_round_trip(
+            200,
+            |random| Game {
+                id: random.below(1000) as u32,
+                draws: (0..=random.below(6))
+                    .map(|_| {
+                        let mut draw = Draw::default();
+                        for _ in 0..=random.below(4) {
+                            let color = colors[random.below(colors.len() as u64) as usize];
+                            *draw.entry(color) = random.below(30) as u32;
+                        }
+                        draw
+                    })
+                    .collect(),
+            },
+            |game| game.to_string(),
+            |line| Ok::<Game, String>(parse_game(line)),
+        );
+    }
+
+    #[test]
+    fn test_day2_estimate() {
+        // Every handful of 3 cubes is 2 red and 1 blue, which is only likely if that is the whole bag
+        let games = (1..=10)
+            .map(|id| parse_game(&format!("Game {}: 2 red, 1 blue; 1 blue, 2 red", id)))
+            .collect::<Vec<Game>>();
+        let estimates = estimate_bag(&games, 50);
+        assert_eq!(
+            vec![
+                "blue: 1 (95% confidence interval 1..1)",
+                "red: 2 (95% confidence interval 2..2)"
+            ],
+            estimates
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<String>>()
+        );
+
+        // Counts seen above the limit are kept, but flagged like every color reaching its limit
+        let input = vec![
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green".to_string(),
+            "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red".to_string(),
+            "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red".to_string(),
+        ];
+        let (_, options) =
+            Options::parse(&["--estimate".to_string(), "--max-cubes=10".to_string()]);
+        let (_, report) = solve(&input, &options).unwrap();
+        assert_eq!(
+            vec![
+                "blue: 15 (95% confidence interval 15..15) at the search limit",
+                "green: 13 (95% confidence interval 13..13) at the search limit",
+                "red: 20 (95% confidence interval 20..20) at the search limit"
+            ],
+            report
+        );
+
+        let (_, options) =
+            Options::parse(&["--estimate".to_string(), "--max-cubes=many".to_string()]);
+        assert_eq!(
+            Err("Maximum number of cubes must be a number: many".to_string()),
+            solve(&input, &options)
+        );
+    }
+
+    #[bench]
+    fn bench_day2_both(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day2.txt");
+        b.iter(|| {
+            solve(&input, &Options::default()).unwrap();
+        });
+    }
+}
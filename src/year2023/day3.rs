@@ -0,0 +1,587 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::options::Options;
+use crate::registry::Parts;
+use crate::repl::Explore;
+
+#[derive(Debug)]
+pub struct Engine {
+    parts: BTreeMap<Position, char>,
+    /// Which of the symbols mark part numbers and which are gears
+    rules: Rules,
+    numbers: Vec<Number>,
+    width: i64,
+    height: i64,
+    /// Index into numbers for every cell covered by a number, row by row
+    occupancy: Vec<Option<usize>>,
+}
+
+#[derive(Debug, Hash, Eq, PartialEq, Clone, Copy, PartialOrd, Ord)]
+pub struct Position {
+    // We take signed integer so we can represent negative positions (above the first row and before the first column)
+    pub y: i64,
+    pub x: i64,
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// A number of the schematic with the position of its first digit and its number of digits
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Number {
+    pub position: Position,
+    pub length: i64,
+    pub value: u64,
+}
+
+impl fmt::Display for Number {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} at {} length {}",
+            self.value, self.position, self.length
+        )
+    }
+}
+
+impl Engine {
+    /// Uses the rules of the puzzle unless others are given in the options, fails for invalid options
+    pub fn parse(input_lines: &Vec<String>, options: &Options) -> Result<Engine, String> {
+        Ok(parse_engine(input_lines, Rules::from_options(options)?))
+    }
+
+    /// The number covering the position, if any
+    pub fn number_covering(self: &Engine, position: &Position) -> Option<Number> {
+        self.number_at(position).map(|index| self.numbers[index])
+    }
+
+    /// The numbers touching the position, including diagonally, in reading order
+    pub fn numbers_touching(self: &Engine, position: &Position) -> Vec<Number> {
+        self.adjacent_numbers(position)
+            .into_iter()
+            .map(|index| self.numbers[index])
+            .collect()
+    }
+
+    /// The part symbols in the cells around a number, in reading order
+    pub fn symbols_touched_by(self: &Engine, number: &Number) -> Vec<(Position, char)> {
+        let mut symbols = vec![];
+        for y in (number.position.y - 1)..(number.position.y + 2) {
+            for x in (number.position.x - 1)..(number.position.x + number.length + 1) {
+                if let Some(symbol) = self.parts.get(&Position { x, y }) {
+                    if self.rules.part_symbols.contains(*symbol) {
+                        symbols.push((Position { x, y }, *symbol));
+                    }
+                }
+            }
+        }
+        symbols
+    }
+
+    /// The numbers not touching any part symbol
+    pub fn non_part_numbers(self: &Engine) -> Vec<Number> {
+        self.numbers
+            .iter()
+            .filter(|n| self.symbols_touched_by(n).is_empty())
+            .cloned()
+            .collect()
+    }
+
+    /// Sum of the numbers touching a part symbol
+    fn part_sum(self: &Engine) -> u64 {
+        self.numbers
+            .iter()
+            .filter(|n| !self.symbols_touched_by(n).is_empty())
+            .map(|n| n.value)
+            .sum()
+    }
+
+    /// The numbers of every gear combined by the gear rule, summed over all gears
+    fn gear_ratios(self: &Engine) -> u64 {
+        let gear = &self.rules.gear;
+        self.parts
+            .iter()
+            .filter(|(_, symbol)| gear.symbols.contains(*symbol))
+            .map(|(pos, _)| self.adjacent_numbers(pos))
+            .filter(|adjacent| adjacent.len() == gear.neighbors)
+            .map(|adjacent| {
+                let values = adjacent
+                    .iter()
+                    .map(|index| self.numbers[*index].value)
+                    .collect::<Vec<u64>>();
+                gear.aggregation.apply(&values)
+            })
+            .sum()
+    }
+
+    /// Index of the number covering the position, None for empty cells and positions outside the schematic
+    fn number_at(self: &Engine, position: &Position) -> Option<usize> {
+        if position.x < 0 || position.y < 0 || position.x >= self.width || position.y >= self.height
+        {
+            return None;
+        }
+        self.occupancy[(position.y * self.width + position.x) as usize]
+    }
+
+    /// Indices of all numbers touching the position, including diagonally, in reading order
+    fn adjacent_numbers(self: &Engine, position: &Position) -> Vec<usize> {
+        let mut adjacent = vec![];
+        for y in (position.y - 1)..(position.y + 2) {
+            for x in (position.x - 1)..(position.x + 2) {
+                if let Some(index) = self.number_at(&Position { x, y }) {
+                    // Neighboring cells of the same row can belong to the same number
+                    if adjacent.last() != Some(&index) {
+                        adjacent.push(index);
+                    }
+                }
+            }
+        }
+        adjacent
+    }
+}
+
+fn parse_engine(input_lines: &Vec<String>, rules: Rules) -> Engine {
+    let mut parts = BTreeMap::new();
+    let mut numbers = vec![];
+
+    for (p_y, engine_line) in input_lines.into_iter().enumerate() {
+        let mut parsing_number_in_progress = false;
+        let mut parsing_number_start_positon = Position { x: 0, y: 0 };
+        let mut parsed_value = 0u64;
+        for (p_x, entry) in engine_line.chars().enumerate() {
+            // We cannot be sure if usize fits into i64 at compile time, but we don't expect such long liines
+            // and we can take the struct initialization shortcut with variable names x and y
+            let x: i64 = p_x.try_into().unwrap();
+            let y: i64 = p_y.try_into().unwrap();
+            if parsing_number_in_progress && !entry.is_ascii_digit() {
+                parsing_number_in_progress = false;
+                numbers.push(Number {
+                    position: parsing_number_start_positon,
+                    length: x - parsing_number_start_positon.x,
+                    value: parsed_value,
+                });
+            }
+            match entry {
+                entry if entry.is_ascii_digit() => {
+                    if parsing_number_in_progress {
+                        parsed_value *= 10;
+                    } else {
+                        parsing_number_in_progress = true;
+                        parsing_number_start_positon = Position { x, y };
+                        parsed_value = 0;
+                    }
+                    parsed_value += entry.to_digit(10).unwrap() as u64;
+                }
+                '.' => {}
+                entry if entry.is_ascii() => {
+                    parts.insert(Position { x, y }, entry);
+                }
+                _ => {
+                    // Should only happen if there is a problem with the input data
+                }
+            }
+        }
+        if parsing_number_in_progress {
+            let line_length: i64 = engine_line.chars().count().try_into().unwrap();
+            numbers.push(Number {
+                position: parsing_number_start_positon,
+                length: line_length - parsing_number_start_positon.x,
+                value: parsed_value,
+            });
+        }
+    }
+
+    let width = input_lines
+        .iter()
+        .map(|l| l.chars().count())
+        .max()
+        .unwrap_or(0) as i64;
+    let height = input_lines.len() as i64;
+    let mut occupancy = vec![None; (width * height) as usize];
+    for (index, number) in numbers.iter().enumerate() {
+        let start = number.position.y * width + number.position.x;
+        for cell in start..(start + number.length) {
+            occupancy[cell as usize] = Some(index);
+        }
+    }
+
+    Engine {
+        parts,
+        rules,
+        numbers,
+        width,
+        height,
+        occupancy,
+    }
+}
+
+/// The characters that make the numbers touching them part numbers
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SymbolClass {
+    /// Every character except digits and '.'
+    Any,
+    Only(BTreeSet<char>),
+}
+
+impl SymbolClass {
+    fn contains(self: &SymbolClass, symbol: char) -> bool {
+        match self {
+            SymbolClass::Any => true,
+            SymbolClass::Only(symbols) => symbols.contains(&symbol),
+        }
+    }
+}
+
+/// How the numbers around a gear are combined to its ratio
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Aggregation {
+    Product,
+    Sum,
+    Max,
+}
+
+impl Aggregation {
+    fn from_name(name: &str) -> Option<Aggregation> {
+        match name {
+            "product" => Some(Aggregation::Product),
+            "sum" => Some(Aggregation::Sum),
+            "max" => Some(Aggregation::Max),
+            _ => None,
+        }
+    }
+
+    fn apply(self: &Aggregation, values: &[u64]) -> u64 {
+        match self {
+            Aggregation::Product => values.iter().product(),
+            Aggregation::Sum => values.iter().sum(),
+            Aggregation::Max => *values.iter().max().unwrap_or(&0),
+        }
+    }
+}
+
+/// A gear is one of the gear symbols touching exactly the given number of numbers. The gear
+/// symbols don't have to be part symbols.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct GearRule {
+    symbols: BTreeSet<char>,
+    neighbors: usize,
+    aggregation: Aggregation,
+}
+
+/// The rules of the schematic, by default the ones of the puzzle: every symbol marks part numbers
+/// and a gear is a `*` touching exactly two numbers, its ratio is their product.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Rules {
+    part_symbols: SymbolClass,
+    gear: GearRule,
+}
+
+impl Default for Rules {
+    fn default() -> Rules {
+        Rules {
+            part_symbols: SymbolClass::Any,
+            gear: GearRule {
+                symbols: BTreeSet::from(['*']),
+                neighbors: 2,
+                aggregation: Aggregation::Product,
+            },
+        }
+    }
+}
+
+impl Rules {
+    /// Reads `--symbols`, `--gear-symbols`, `--gear-neighbors` and `--gear-aggregation`,
+    /// falling back to the default rules for the options not given
+    fn from_options(options: &Options) -> Result<Rules, String> {
+        let mut rules = Rules::default();
+        if let Some(symbols) = options.get("symbols") {
+            rules.part_symbols = SymbolClass::Only(symbols.chars().collect());
+        }
+        if let Some(symbols) = options.get("gear-symbols") {
+            rules.gear.symbols = symbols.chars().collect();
+        }
+        if let Some(neighbors) = options.get("gear-neighbors") {
+            rules.gear.neighbors = neighbors
+                .parse()
+                .map_err(|_| format!("Number of gear neighbors must be a number: {}", neighbors))?;
+        }
+        if let Some(aggregation) = options.get("gear-aggregation") {
+            rules.gear.aggregation = Aggregation::from_name(aggregation)
+                .ok_or(format!("Unknown gear aggregation {}", aggregation))?;
+        }
+        Ok(rules)
+    }
+}
+
+/// Sums the numbers touching a part symbol and the gear ratios in one pass over the symbols
+fn calculate_part_sum_and_gear_ratios(engine: &Engine) -> (u64, u64) {
+    let rules = &engine.rules;
+    let mut is_part = vec![false; engine.numbers.len()];
+    let mut gear_ratios = 0;
+    for (pos, symbol) in engine.parts.iter() {
+        let is_part_symbol = rules.part_symbols.contains(*symbol);
+        let is_gear_symbol = rules.gear.symbols.contains(symbol);
+        if !is_part_symbol && !is_gear_symbol {
+            continue;
+        }
+        let adjacent = engine.adjacent_numbers(pos);
+        if is_part_symbol {
+            for index in adjacent.iter() {
+                is_part[*index] = true;
+            }
+        }
+        if is_gear_symbol && adjacent.len() == rules.gear.neighbors {
+            let values = adjacent
+                .iter()
+                .map(|index| engine.numbers[*index].value)
+                .collect::<Vec<u64>>();
+            gear_ratios += rules.gear.aggregation.apply(&values);
+        }
+    }
+
+    let part_sum = engine
+        .numbers
+        .iter()
+        .zip(is_part.iter())
+        .filter(|(_, is_part)| **is_part)
+        .map(|(number, _)| number.value)
+        .sum();
+    (part_sum, gear_ratios)
+}
+
+/// Uses the rules of the puzzle unless others are given in the options
+pub fn solve(input_lines: &Vec<String>, options: &Options) -> Result<(u64, u64), String> {
+    let engine = Engine::parse(input_lines, options)?;
+    Ok(calculate_part_sum_and_gear_ratios(&engine))
+}
+
+/// Profiling solves the parts on their own, `solve` finds both in one pass
+impl Parts for Engine {
+    fn part_1(&self) -> Result<String, String> {
+        Ok(self.part_sum().to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        Ok(self.gear_ratios().to_string())
+    }
+}
+
+fn parse_position(arguments: &[&str]) -> Option<Position> {
+    let x = arguments.first()?.parse().ok()?;
+    let y = arguments.get(1)?.parse().ok()?;
+    Some(Position { x, y })
+}
+
+fn format_numbers(numbers: &[Number]) -> String {
+    numbers
+        .iter()
+        .map(|n| n.to_string())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+impl Explore for Engine {
+    fn help(self: &Engine) -> Vec<&'static str> {
+        vec![
+            "symbol <x> <y>             lists the numbers touching the symbol at column x and row y",
+            "number <x> <y>             shows the number covering column x and row y and the symbols it touches",
+            "non-parts                  lists the numbers not touching any part symbol",
+        ]
+    }
+
+    fn query(self: &Engine, command: &str, arguments: &[&str]) -> Option<String> {
+        let output = match (command, parse_position(arguments)) {
+            ("symbol", Some(position)) => match self.parts.get(&position) {
+                Some(symbol) => {
+                    let numbers = self.numbers_touching(&position);
+                    if numbers.is_empty() {
+                        format!("{} at {} touches no number", symbol, position)
+                    } else {
+                        format_numbers(&numbers)
+                    }
+                }
+                None => format!("There is no symbol at {}", position),
+            },
+            ("number", Some(position)) => match self.number_covering(&position) {
+                Some(number) => {
+                    let symbols = self
+                        .symbols_touched_by(&number)
+                        .iter()
+                        .map(|(p, symbol)| format!("{} at {}", symbol, p))
+                        .collect::<Vec<String>>();
+                    if symbols.is_empty() {
+                        format!("{} touches no part symbol", number)
+                    } else {
+                        format!("{} touches {}", number, symbols.join(", "))
+                    }
+                }
+                None => format!("There is no number at {}", position),
+            },
+            ("non-parts", _) => format_numbers(&self.non_part_numbers()),
+            ("symbol", None) | ("number", None) => "Please specify the position as x y".to_string(),
+            _ => return None,
+        };
+        Some(output)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    extern crate test;
+    use test::Bencher;
+
+    #[test]
+    fn test_day3() {
+        let result_1 = 4361;
+        let result_2 = 467835;
+        let input = vec![
+            "467..114..".to_string(),
+            "...*......".to_string(),
+            "..35..633.".to_string(),
+            "......#...".to_string(),
+            "617*......".to_string(),
+            ".....+.58.".to_string(),
+            "..592.....".to_string(),
+            "......755.".to_string(),
+            "...$.*....".to_string(),
+            ".664.598..".to_string(),
+        ];
+        let (output_1, output_2) = solve(&input, &Options::default()).unwrap();
+        assert_eq!(result_1, output_1);
+        assert_eq!(result_2, output_2);
+
+        // A number at the end of a line and a symbol touching the same number twice
+        let input = vec!["..12".to_string(), "*.3*".to_string(), "..45".to_string()];
+        assert_eq!(Ok((60, 0)), solve(&input, &Options::default()));
+    }
+
+    #[test]
+    fn test_day3_queries() {
+        let input = vec![
+            "467..114..".to_string(),
+            "...*......".to_string(),
+            "..35..633.".to_string(),
+            "......#...".to_string(),
+            "617*......".to_string(),
+            ".....+.58.".to_string(),
+            "..592.....".to_string(),
+            "......755.".to_string(),
+            "...$.*....".to_string(),
+            ".664.598..".to_string(),
+        ];
+        let engine = Engine::parse(&input, &Options::default()).unwrap();
+        assert_eq!(
+            vec![
+                Number {
+                    position: Position { x: 0, y: 0 },
+                    length: 3,
+                    value: 467
+                },
+                Number {
+                    position: Position { x: 2, y: 2 },
+                    length: 2,
+                    value: 35
+                }
+            ],
+            engine.numbers_touching(&Position { x: 3, y: 1 })
+        );
+        assert_eq!(
+            "114 at (5, 0) length 3\n58 at (7, 5) length 2",
+            engine.query("non-parts", &[]).unwrap()
+        );
+        assert_eq!(
+            "592 at (2, 6) length 3 touches + at (5, 5)",
+            engine.query("number", &["3", "6"]).unwrap()
+        );
+        assert_eq!(
+            "There is no symbol at (0, 0)",
+            engine.query("symbol", &["0", "0"]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_day3_rules() {
+        let input = vec![
+            "467..114..".to_string(),
+            "...*......".to_string(),
+            "..35..633.".to_string(),
+            "......#...".to_string(),
+            "617*......".to_string(),
+            ".....+.58.".to_string(),
+            "..592.....".to_string(),
+            "......755.".to_string(),
+            "...$.*....".to_string(),
+            ".664.598..".to_string(),
+        ];
+        let rules = Rules {
+            part_symbols: SymbolClass::Only(BTreeSet::from(['#'])),
+            gear: GearRule {
+                aggregation: Aggregation::Sum,
+                ..Rules::default().gear
+            },
+        };
+        let engine = parse_engine(&input, rules);
+        assert_eq!(
+            (633, 467 + 35 + 755 + 598),
+            calculate_part_sum_and_gear_ratios(&engine)
+        );
+
+        // The queries follow the restricted symbols like the sum does
+        let (_, options) = Options::parse(&["--symbols=#$".to_string()]);
+        let engine = Engine::parse(&input, &options).unwrap();
+        assert_eq!(
+            "467 at (0, 0) length 3 touches no part symbol",
+            engine.query("number", &["0", "0"]).unwrap()
+        );
+        let part_sum = engine.numbers.iter().map(|n| n.value).sum::<u64>()
+            - engine
+                .non_part_numbers()
+                .iter()
+                .map(|n| n.value)
+                .sum::<u64>();
+        assert_eq!(633 + 664, part_sum);
+        assert_eq!(Ok(part_sum), solve(&input, &options).map(|(sum, _)| sum));
+
+        let args = ["--gear-neighbors=1", "--gear-aggregation=max"].map(|a| a.to_string());
+        let (_, options) = Options::parse(&args);
+        assert_eq!(Ok((4361, 617)), solve(&input, &options));
+        // The parts on their own agree with the single pass
+        let engine = Engine::parse(&input, &options).unwrap();
+        assert_eq!(
+            (Ok("4361".to_string()), Ok("617".to_string())),
+            (engine.part_1(), engine.part_2())
+        );
+
+        let invalid = |arg: &str| solve(&input, &Options::parse(&[arg.to_string()]).1);
+        assert_eq!(
+            Err("Number of gear neighbors must be a number: two".to_string()),
+            invalid("--gear-neighbors=two")
+        );
+        assert_eq!(
+            Err("Unknown gear aggregation mean".to_string()),
+            invalid("--gear-aggregation=mean")
+        );
+    }
+
+    #[bench]
+    fn bench_day3_parse(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day3.txt");
+        b.iter(|| {
+            parse_engine(&input, Rules::default());
+        });
+    }
+
+    #[bench]
+    fn bench_day3_both(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day3.txt");
+        b.iter(|| {
+            solve(&input, &Options::default()).unwrap();
+        });
+    }
+}
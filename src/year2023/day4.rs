@@ -0,0 +1,591 @@
+use std::collections::BTreeSet;
+use std::fmt;
+
+use crate::options::Options;
+use crate::registry::Parts;
+
+const NUMBER_SET_WORDS: usize = 16;
+
+/// Largest number a card can hold
+const MAX_NUMBER: u32 = (NUMBER_SET_WORDS * 64 - 1) as u32;
+
+/// Fixed-width bitset of the numbers 0 to MAX_NUMBER
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+struct NumberSet {
+    words: [u64; NUMBER_SET_WORDS],
+}
+
+impl NumberSet {
+    fn from_numbers(numbers: &[u32]) -> NumberSet {
+        let mut set = NumberSet::default();
+        for number in numbers {
+            set.words[*number as usize / 64] |= 1 << (number % 64);
+        }
+        set
+    }
+
+    fn count_common(self: &NumberSet, other: &NumberSet) -> u32 {
+        self.words
+            .iter()
+            .zip(other.words.iter())
+            .map(|(a, b)| (a & b).count_ones())
+            .sum()
+    }
+}
+
+/// The numbers are kept in input order, so a card can be written back as it was read
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Card {
+    id: u32,
+    winning_numbers: Vec<u32>,
+    my_numbers: Vec<u32>,
+    winning_set: NumberSet,
+    my_set: NumberSet,
+}
+
+impl Card {
+    /// Fails for numbers above MAX_NUMBER
+    fn new(id: u32, winning_numbers: Vec<u32>, my_numbers: Vec<u32>) -> Result<Card, String> {
+        if let Some(number) = winning_numbers
+            .iter()
+            .chain(my_numbers.iter())
+            .find(|n| **n > MAX_NUMBER)
+        {
+            return Err(format!(
+                "Card {}: number {} is larger than {}",
+                id, number, MAX_NUMBER
+            ));
+        }
+        Ok(Card {
+            id,
+            winning_set: NumberSet::from_numbers(&winning_numbers),
+            my_set: NumberSet::from_numbers(&my_numbers),
+            winning_numbers,
+            my_numbers,
+        })
+    }
+
+    /// Numbers appearing several times on one side count once
+    fn count_matching_numbers(self: &Card) -> u32 {
+        self.winning_set.count_common(&self.my_set)
+    }
+}
+
+/// Formats like the input line with the numbers right aligned in columns of two. The width of the
+/// format aligns the id, e.g. `format!("{:3}", card)` gives `Card   1: 41 48 |  6 31`. The puzzle
+/// input aligns all ids to the longest one.
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Card {:>1$}:", self.id, f.width().unwrap_or(0))?;
+        for number in self.winning_numbers.iter() {
+            write!(f, " {:>2}", number)?;
+        }
+        write!(f, " |")?;
+        for number in self.my_numbers.iter() {
+            write!(f, " {:>2}", number)?;
+        }
+        Ok(())
+    }
+}
+
+/// Generic function which parses the input of format
+/// xxx <id>: payload
+/// into id and payload.
+fn parse_id_payload(input_line: &str) -> Result<(u32, String), String> {
+    let (head, payload) = input_line
+        .split_once(":")
+        .ok_or_else(|| format!("There was no : in {}", input_line))?;
+    let id = head
+        .split_whitespace()
+        .last()
+        .and_then(|x| x.parse::<u32>().ok())
+        .ok_or_else(|| format!("Cannot parse the id in {}", head))?;
+    Ok((id, payload.to_string()))
+}
+
+/// Numbers separated by any amount of spaces
+fn parse_numbers(numbers: &str) -> Result<Vec<u32>, String> {
+    numbers
+        .split_whitespace()
+        .map(|x| {
+            x.parse::<u32>()
+                .map_err(|_| format!("Cannot parse number {}", x))
+        })
+        .collect()
+}
+
+fn parse_card(id: u32, payload: &str) -> Result<Card, String> {
+    let (winning, drawn) = payload.split_once("|").ok_or_else(|| {
+        format!(
+            "Card {}: there is no | between the winning and drawn numbers",
+            id
+        )
+    })?;
+    let winning_numbers = parse_numbers(winning).map_err(|e| format!("Card {}: {}", id, e))?;
+    let my_numbers = parse_numbers(drawn).map_err(|e| format!("Card {}: {}", id, e))?;
+
+    Card::new(id, winning_numbers, my_numbers)
+}
+
+/// Parses all cards and checks that the ids run from 1 without gaps, as the copies refer to the
+/// following cards. All problems are reported together.
+fn parse_cards(input: &[String]) -> Result<Vec<Card>, String> {
+    let mut cards = vec![];
+    let mut errors = vec![];
+    let mut seen_ids = BTreeSet::new();
+    let mut last_id = 0;
+    for (line_number, input_line) in input.iter().enumerate() {
+        let (id, payload) = match parse_id_payload(input_line) {
+            Ok(id_payload) => id_payload,
+            Err(message) => {
+                errors.push(format!("line {}: {}", line_number + 1, message));
+                continue;
+            }
+        };
+        let problem = if seen_ids.contains(&id) {
+            Some(format!("duplicate card id {}", id))
+        } else if id <= last_id {
+            Some(format!("card {} is out of order", id))
+        } else if id > last_id + 1 {
+            Some(format!(
+                "card {} is missing before card {}",
+                last_id + 1,
+                id
+            ))
+        } else {
+            None
+        };
+        seen_ids.insert(id);
+        last_id = last_id.max(id);
+        if let Some(problem) = problem {
+            errors.push(format!("line {}: {}", line_number + 1, problem));
+            continue;
+        }
+        match parse_card(id, &payload) {
+            Ok(card) => cards.push(card),
+            Err(message) => errors.push(format!("line {}: {}", line_number + 1, message)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(cards)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+/// Points of a card by its number of matches, no matches are always worth nothing
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Scoring {
+    /// 1, 2, 4, 8, ... points, the rule of the puzzle
+    Geometric,
+    /// One point per match
+    Linear,
+    /// Points for 1, 2, 3, ... matches, more matches than entries get the last entry
+    Table(Vec<u32>),
+}
+
+impl Scoring {
+    /// Reads `geometric`, `linear` or `table:1,3,6`
+    fn from_name(name: &str) -> Result<Scoring, String> {
+        match name.split_once(":") {
+            None if name == "geometric" => Ok(Scoring::Geometric),
+            None if name == "linear" => Ok(Scoring::Linear),
+            Some(("table", values)) => values
+                .split(",")
+                .map(|v| v.trim().parse::<u32>())
+                .collect::<Result<Vec<u32>, _>>()
+                .map(Scoring::Table)
+                .map_err(|_| {
+                    format!(
+                        "Points table must be numbers separated by commas: {}",
+                        values
+                    )
+                }),
+            _ => Err(format!("Unknown scoring {}", name)),
+        }
+    }
+
+    /// Fails when the points don't fit in a u32, geometric scoring from 33 matches on
+    fn points(self: &Scoring, matches: u32) -> Result<u32, String> {
+        if matches == 0 {
+            return Ok(0);
+        }
+        match self {
+            // wins -> points: 1 -> 1, 2 -> 2, 3 -> 4, 4 -> 8, 5 -> 16 ... wins -> 2^(wins-1)
+            Scoring::Geometric => 2u32.checked_pow(matches - 1).ok_or_else(|| {
+                format!(
+                    "{} matches are worth more than {} points",
+                    matches,
+                    u32::MAX
+                )
+            }),
+            Scoring::Linear => Ok(matches),
+            Scoring::Table(table) => Ok(*table
+                .get(matches as usize - 1)
+                .or(table.last())
+                .unwrap_or(&0)),
+        }
+    }
+}
+
+/// Which cards a card wins copies of, by its number of matches
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cascade {
+    /// The next cards, one per match, the rule of the puzzle
+    Next,
+    /// Every n-th following card, one per match
+    Stride(usize),
+    /// No copies at all
+    None,
+}
+
+impl Cascade {
+    /// Reads `next`, `stride:<n>` or `none`
+    fn from_name(name: &str) -> Result<Cascade, String> {
+        match name.split_once(":") {
+            None if name == "next" => Ok(Cascade::Next),
+            None if name == "none" => Ok(Cascade::None),
+            Some(("stride", stride)) => match stride.parse::<usize>() {
+                Ok(stride) if stride > 0 => Ok(Cascade::Stride(stride)),
+                _ => Err(format!("Stride must be a positive number: {}", stride)),
+            },
+            _ => Err(format!("Unknown cascade {}", name)),
+        }
+    }
+
+    /// Positions of the won cards in the table. They always follow the winning card, so the
+    /// instances of a card are final once it is reached. Cards past the end of the table are not won.
+    fn won_cards(self: &Cascade, index: usize, matches: u32, table_length: usize) -> Vec<usize> {
+        let stride = match self {
+            Cascade::Next => 1,
+            Cascade::Stride(stride) => *stride,
+            Cascade::None => return vec![],
+        };
+        (1..=matches as usize)
+            .map(|n| index + n * stride)
+            .take_while(|won| *won < table_length)
+            .collect()
+    }
+}
+
+/// What happened to one card: its matches, its points per instance and the instances it
+/// received from earlier cards as (card id, instances)
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CardTrace {
+    id: u32,
+    matches: u32,
+    points: u32,
+    instances: u32,
+    received: Vec<(u32, u32)>,
+}
+
+impl fmt::Display for CardTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sources = vec!["1 original".to_string()];
+        sources.extend(
+            self.received
+                .iter()
+                .map(|(id, count)| format!("{} from card {}", count, id)),
+        );
+        write!(
+            f,
+            "Card {}: {} matches, {} points, {} instances ({})",
+            self.id,
+            self.matches,
+            self.points,
+            self.instances,
+            sources.join(", ")
+        )
+    }
+}
+
+/// Scores the cards and plays the cascade of copies. Only the original of every card scores points.
+fn play(
+    cards: &[Card],
+    scoring: &Scoring,
+    cascade: &Cascade,
+) -> Result<(u32, u32, Vec<CardTrace>), String> {
+    let mut points = 0;
+    let mut total_cards = 0;
+    // Instances of every card, by position in the table
+    let mut instances = vec![1u32; cards.len()];
+    let mut received = vec![vec![]; cards.len()];
+    let mut trace = vec![];
+
+    for (index, card) in cards.iter().enumerate() {
+        let matching_numbers = card.count_matching_numbers();
+        let this_card_instances = instances[index];
+        let this_card_points = scoring
+            .points(matching_numbers)
+            .map_err(|e| format!("Card {}: {}", card.id, e))?;
+        total_cards += this_card_instances;
+        points = add_points(points, this_card_points)?;
+
+        for won in cascade.won_cards(index, matching_numbers, cards.len()) {
+            instances[won] += this_card_instances;
+            received[won].push((card.id, this_card_instances));
+        }
+        trace.push(CardTrace {
+            id: card.id,
+            matches: matching_numbers,
+            points: this_card_points,
+            instances: this_card_instances,
+            received: std::mem::take(&mut received[index]),
+        });
+    }
+    Ok((points, total_cards, trace))
+}
+
+fn add_points(total: u32, points: u32) -> Result<u32, String> {
+    total
+        .checked_add(points)
+        .ok_or_else(|| format!("The points add up to more than {}", u32::MAX))
+}
+
+/// The cards with the rules to play them by
+pub struct Scratchcards {
+    cards: Vec<Card>,
+    scoring: Scoring,
+    cascade: Cascade,
+}
+
+impl Scratchcards {
+    /// Uses the scoring and cascade of the puzzle unless `--scoring` or `--cascade` are given
+    pub fn parse(input: &[String], options: &Options) -> Result<Scratchcards, String> {
+        Ok(Scratchcards {
+            cards: parse_cards(input)?,
+            scoring: Scoring::from_name(options.get("scoring").unwrap_or("geometric"))?,
+            cascade: Cascade::from_name(options.get("cascade").unwrap_or("next"))?,
+        })
+    }
+}
+
+impl Parts for Scratchcards {
+    fn part_1(&self) -> Result<String, String> {
+        let mut points = 0;
+        for card in self.cards.iter() {
+            let card_points = self
+                .scoring
+                .points(card.count_matching_numbers())
+                .map_err(|e| format!("Card {}: {}", card.id, e))?;
+            points = add_points(points, card_points)?;
+        }
+        Ok(points.to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        let (_, total_cards, _) = play(&self.cards, &self.scoring, &self.cascade)?;
+        Ok(total_cards.to_string())
+    }
+}
+
+/// With `--trace` every card is reported with how it scored and where its instances came from.
+pub fn solve(input: &[String], options: &Options) -> Result<((u32, u32), Vec<String>), String> {
+    let scratchcards = Scratchcards::parse(input, options)?;
+    let (points, total_cards, trace) = play(
+        &scratchcards.cards,
+        &scratchcards.scoring,
+        &scratchcards.cascade,
+    )?;
+    let report = if options.is_set("trace") {
+        trace.iter().map(|t| t.to_string()).collect()
+    } else {
+        vec![]
+    };
+    Ok(((points, total_cards), report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    use crate::testing::check_round_trip;
+    extern crate test;
+    use test::Bencher;
+
+    #[test]
+    fn test_day4() {
+        let result_1 = 13;
+        let result_2 = 30;
+        let input = vec![
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53".to_string(),
+            "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19".to_string(),
+            "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1".to_string(),
+            "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83".to_string(),
+            "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36".to_string(),
+            "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11".to_string(),
+        ];
+        let ((output_1, output_2), report) = solve(&input, &Options::default()).unwrap();
+        assert_eq!(result_1, output_1);
+        assert_eq!(result_2, output_2);
+        assert!(report.is_empty());
+
+        let (_, options) = Options::parse(&["--trace".to_string()]);
+        let (_, report) = solve(&input, &options).unwrap();
+        assert_eq!(6, report.len());
+        assert_eq!(
+            "Card 6: 0 matches, 0 points, 1 instances (1 original)",
+            report[5]
+        );
+
+        // The last card wins copies past the end of the table, which don't exist
+        let input = vec![
+            "Card 1: 1 2 | 3 4".to_string(),
+            "Card 2: 5 6 | 5 6".to_string(),
+            "Card 3: 7 8 | 7 9".to_string(),
+        ];
+        assert_eq!(
+            Ok(((2 + 1, 1 + 1 + 2), vec![])),
+            solve(&input, &Options::default())
+        );
+    }
+
+    #[test]
+    fn test_day4_rules() {
+        let input = vec![
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53".to_string(),
+            "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19".to_string(),
+            "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1".to_string(),
+            "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83".to_string(),
+            "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36".to_string(),
+            "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11".to_string(),
+        ];
+        let cards = parse_cards(&input).unwrap();
+        let (points, total_cards, trace) =
+            play(&cards, &Scoring::Geometric, &Cascade::Next).unwrap();
+        assert_eq!((13, 30), (points, total_cards));
+        assert_eq!(
+            "Card 4: 1 matches, 1 points, 8 instances (1 original, 1 from card 1, 2 from card 2, 4 from card 3)",
+            trace[3].to_string()
+        );
+
+        let (points, total_cards, _) = play(&cards, &Scoring::Linear, &Cascade::None).unwrap();
+        assert_eq!((4 + 2 + 2 + 1, 6), (points, total_cards));
+        let (points, total_cards, _) = play(
+            &cards,
+            &Scoring::from_name("table:10,20").unwrap(),
+            &Cascade::from_name("stride:2").unwrap(),
+        )
+        .unwrap();
+        // Card 1 wins cards 3 and 5, card 2 cards 4 and 6, card 3 card 5 and card 4 card 6
+        assert_eq!(
+            (20 + 20 + 20 + 10, 1 + 1 + 2 + 2 + 4 + 4),
+            (points, total_cards)
+        );
+        assert!(Scoring::from_name("quadratic").is_err());
+    }
+
+    #[test]
+    fn test_day4_validation() {
+        let input = vec![
+            "Card 1: 41 48 | 83 86".to_string(),
+            "Card 3: 13 32 | 61 30".to_string(),
+            "Card 4:  1 21 | 69 2000".to_string(),
+            "Card 2:  1 21 | 69 82".to_string(),
+            "Card 1: 41 92 | 59 84".to_string(),
+        ];
+        assert_eq!(
+            Err([
+                "line 2: card 2 is missing before card 3",
+                "line 3: Card 4: number 2000 is larger than 1023",
+                "line 4: card 2 is out of order",
+                "line 5: duplicate card id 1",
+            ]
+            .join("\n")),
+            solve(&input, &Options::default())
+        );
+
+        let input = vec![
+            "Card 1 41 48 | 83 86".to_string(),
+            "Card x: 13 32 | 61 30".to_string(),
+            "Card 1: 13 32 61 30".to_string(),
+            "Card 2: 13 3x | 61 30".to_string(),
+        ];
+        assert_eq!(
+            Err([
+                "line 1: There was no : in Card 1 41 48 | 83 86",
+                "line 2: Cannot parse the id in Card x",
+                "line 3: Card 1: there is no | between the winning and drawn numbers",
+                "line 4: Card 2: Cannot parse number 3x",
+            ]
+            .join("\n")),
+            solve(&input, &Options::default())
+        );
+
+        // 33 matches are worth 2^32 points, more than a u32 holds
+        let numbers = (0..33)
+            .map(|n| n.to_string())
+            .collect::<Vec<String>>()
+            .join(" ");
+        let input = vec![format!("Card 1: {} | {}", numbers, numbers)];
+        assert_eq!(
+            Err("Card 1: 33 matches are worth more than 4294967295 points".to_string()),
+            solve(&input, &Options::default())
+        );
+        let scratchcards = Scratchcards::parse(&input, &Options::default()).unwrap();
+        assert!(scratchcards.part_1().is_err());
+        let (_, options) = Options::parse(&["--scoring=linear".to_string()]);
+        assert_eq!(Ok(((33, 1), vec![])), solve(&input, &options));
+    }
+
+    #[test]
+    fn test_day4_round_trip() {
+        let input = vec![
+            "Card   1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53".to_string(),
+            "Card  10: 13 32 20 16 61 | 61 30 68 82 17 32 24 19".to_string(),
+            "Card 100:  1 21 53 59 44 | 69 82 63 72 16 21 14  1".to_string(),
+        ];
+        let cards = input
+            .iter()
+            .map(|l| {
+                let (id, payload) = parse_id_payload(l).unwrap();
+                parse_card(id, &payload).unwrap()
+            })
+            .collect::<Vec<Card>>();
+        assert_eq!(
+            input,
+            cards
+                .iter()
+                .map(|c| format!("{:3}", c))
+                .collect::<Vec<String>>()
+        );
+
+        // No numbers on one side, numbers wider than the columns and repeated numbers
+        let cards = [
+            Card::new(7, vec![], vec![1, 2, 3]),
+            Card::new(8, vec![5, 5], vec![]),
+            Card::new(999, vec![0, 123, MAX_NUMBER], vec![99, 100, 7]),
+        ];
+        for card in cards {
+            let card = card.unwrap();
+            let (id, payload) = parse_id_payload(&card.to_string()).unwrap();
+            assert_eq!(Ok(card), parse_card(id, &payload));
+        }
+
+        check_round_trip(
+            200,
+            |random| {
+                Card::new(
+                    random.below(1000) as u32,
+                    (0..random.below(10))
+                        .map(|_| random.below(100) as u32)
+                        .collect(),
+                    (0..random.below(25))
+                        .map(|_| random.below(MAX_NUMBER as u64 + 1) as u32)
+                        .collect(),
+                )
+                .unwrap()
+            },
+            |card| card.to_string(),
+            |line| parse_id_payload(line).and_then(|(id, payload)| parse_card(id, &payload)),
+        );
+    }
+
+    #[bench]
+    fn bench_day4_both(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day4.txt");
+        b.iter(|| {
+            solve(&input, &Options::default()).unwrap();
+        });
+    }
+}
@@ -0,0 +1,875 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+use crate::registry::Parts;
+use crate::repl::Explore;
+
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Copy)]
+struct Range {
+    start: i64,
+    end: i64,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Copy)]
+struct Mapping {
+    start: i64,
+    end: i64,
+    shift: i64,
+}
+
+impl Mapping {
+    fn contains(self: &Mapping, value: i64) -> bool {
+        value >= self.start && value <= self.end
+    }
+
+    fn map(self: &Mapping, value: i64) -> i64 {
+        if self.contains(value) {
+            value + self.shift
+        } else {
+            value
+        }
+    }
+}
+
+/// A problem in the table of a map
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Problem {
+    /// An entry with a length below 1, it maps nothing
+    Empty { line: usize, length: i64 },
+    /// An entry whose source or destination range, or the value right after it, does not fit
+    /// into the value type
+    Overflow { line: usize },
+    /// Two mappings share source values, only the lower one is applied to them
+    OverlappingSources(Mapping, Mapping),
+    /// Two mappings share destination values, the map is not injective
+    OverlappingDestinations(Mapping, Mapping),
+}
+
+impl Problem {
+    /// Problems that make the result of the map differ from what the table says. Overlapping
+    /// destinations still give a well-defined map, they only prevent inverting it.
+    fn is_fatal(self: &Problem) -> bool {
+        !matches!(self, Problem::OverlappingDestinations(..))
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format_mapping = |m: &Mapping| format!("[{}, {}] {:+}", m.start, m.end, m.shift);
+        match self {
+            Problem::Empty { line, length } => {
+                write!(f, "line {}: entry of length {} maps nothing", line, length)
+            }
+            Problem::Overflow { line } => write!(f, "line {}: entry overflows", line),
+            Problem::OverlappingSources(a, b) => write!(
+                f,
+                "sources of {} and {} overlap",
+                format_mapping(a),
+                format_mapping(b)
+            ),
+            Problem::OverlappingDestinations(a, b) => write!(
+                f,
+                "destinations of {} and {} overlap",
+                format_mapping(a),
+                format_mapping(b)
+            ),
+        }
+    }
+}
+
+/// Maps the values of the source category to the destination category
+#[derive(Debug, Clone)]
+struct AdventMap {
+    source: String,
+    destination: String,
+    mappings: BTreeSet<Mapping>,
+    /// Problems of the entries found while parsing, the overlaps are found by validate
+    problems: Vec<Problem>,
+}
+
+impl AdventMap {
+    fn new(source: &str, destination: &str) -> AdventMap {
+        AdventMap {
+            source: source.to_string(),
+            destination: destination.to_string(),
+            mappings: BTreeSet::new(),
+            problems: vec![],
+        }
+    }
+
+    /// Parses an entry `<destination> <source> <length>`. Empty and overflowing entries are
+    /// not inserted but recorded as problems.
+    fn parse_and_insert(
+        self: &mut AdventMap,
+        line_number: usize,
+        line: &str,
+    ) -> Result<(), String> {
+        let values = line
+            .split_whitespace()
+            .map(|v| v.parse::<i64>())
+            .collect::<Result<Vec<i64>, _>>()
+            .ok()
+            .filter(|v| v.len() == 3)
+            .ok_or(format!(
+                "line {}: cannot parse mapping {}",
+                line_number, line
+            ))?;
+        let (destination, source, length) = (values[0], values[1], values[2]);
+        if length < 1 {
+            self.problems.push(Problem::Empty {
+                line: line_number,
+                length,
+            });
+            return Ok(());
+        }
+        // The breakpoints of the map need the value after the end of the entry
+        let (Some(end), Some(_), Some(shift)) = (
+            source.checked_add(length).map(|after| after - 1),
+            destination.checked_add(length),
+            destination.checked_sub(source),
+        ) else {
+            self.problems.push(Problem::Overflow { line: line_number });
+            return Ok(());
+        };
+        let mapping = Mapping {
+            start: source,
+            end,
+            shift,
+        };
+        if !self.mappings.insert(mapping) {
+            // The same entry twice overlaps with itself
+            self.problems
+                .push(Problem::OverlappingSources(mapping, mapping));
+        }
+        Ok(())
+    }
+
+    /// The problems found while parsing and all overlapping sources and destinations
+    fn validate(self: &AdventMap) -> Vec<Problem> {
+        let mut problems = self.problems.clone();
+        let sources = self.mappings.iter().map(|m| (m.start, m.end, *m));
+        problems.extend(
+            find_overlaps(sources)
+                .into_iter()
+                .map(|(a, b)| Problem::OverlappingSources(a, b)),
+        );
+        let destinations = self
+            .mappings
+            .iter()
+            .map(|m| (m.start + m.shift, m.end + m.shift, *m));
+        problems.extend(
+            find_overlaps(destinations)
+                .into_iter()
+                .map(|(a, b)| Problem::OverlappingDestinations(a, b)),
+        );
+        problems
+    }
+
+    /// The map as contiguous mappings from 0 up to the end of the last mapping, with the gaps filled
+    /// by mappings without shift and neighbors with the same shift merged. Overlapping mappings are
+    /// resolved the way apply resolves them.
+    fn normalize(self: &AdventMap) -> AdventMap {
+        let mut breakpoints = self.breakpoints();
+        breakpoints.insert(0);
+        let points = breakpoints.into_iter().collect::<Vec<i64>>();
+        let mut mappings: Vec<Mapping> = vec![];
+        for piece in points.windows(2) {
+            let shift = self.apply(piece[0]) - piece[0];
+            match mappings.last_mut() {
+                // Neighboring pieces with the same shift are merged
+                Some(last) if last.shift == shift => last.end = piece[1] - 1,
+                _ => mappings.push(Mapping {
+                    start: piece[0],
+                    end: piece[1] - 1,
+                    shift,
+                }),
+            }
+        }
+        let mut normalized = AdventMap::new(&self.source, &self.destination);
+        normalized.mappings.extend(mappings);
+        normalized
+    }
+
+    fn apply(self: &AdventMap, value: i64) -> i64 {
+        for mapping in self.mappings.iter() {
+            if mapping.contains(value) {
+                return mapping.map(value);
+            }
+        }
+        value
+    }
+
+    /// All values the map sends to the value
+    fn preimages(self: &AdventMap, value: i64) -> Vec<i64> {
+        let mut preimages = self
+            .mappings
+            .iter()
+            .map(|m| value - m.shift)
+            .chain([value])
+            .filter(|x| self.apply(*x) == value)
+            .collect::<Vec<i64>>();
+        preimages.sort();
+        preimages.dedup();
+        preimages
+    }
+
+    /// The values where the shift can change: the starts of the mappings and the values right after them
+    fn breakpoints(self: &AdventMap) -> BTreeSet<i64> {
+        self.mappings
+            .iter()
+            .flat_map(|m| [m.start, m.end + 1])
+            .collect()
+    }
+
+    /// Builds the map of a function which has a constant shift from each breakpoint up to the next
+    /// one and no shift before the first and after the last. Unshifted parts are left out and
+    /// neighboring parts with the same shift are merged.
+    fn from_breakpoints(
+        source: &str,
+        destination: &str,
+        breakpoints: &BTreeSet<i64>,
+        function: impl Fn(i64) -> i64,
+    ) -> AdventMap {
+        let mut map = AdventMap::new(source, destination);
+        let points = breakpoints.iter().copied().collect::<Vec<i64>>();
+        for piece in points.windows(2) {
+            let shift = function(piece[0]) - piece[0];
+            if shift == 0 {
+                continue;
+            }
+            let mut mapping = Mapping {
+                start: piece[0],
+                end: piece[1] - 1,
+                shift,
+            };
+            if let Some(last) = map.mappings.last().copied() {
+                if last.end + 1 == mapping.start && last.shift == shift {
+                    map.mappings.remove(&last);
+                    mapping.start = last.start;
+                }
+            }
+            map.mappings.insert(mapping);
+        }
+        map
+    }
+
+    /// The map applying this map and then the next one. The shift of the result can only change
+    /// where the shift of this map changes or where this map reaches a breakpoint of the next one.
+    fn compose(self: &AdventMap, next: &AdventMap) -> AdventMap {
+        let mut breakpoints = self.breakpoints();
+        for point in next.breakpoints() {
+            breakpoints.extend(self.preimages(point));
+        }
+        AdventMap::from_breakpoints(&self.source, &next.destination, &breakpoints, |x| {
+            next.apply(self.apply(x))
+        })
+    }
+
+    /// The map back from the destination to the source. Only a bijection can be inverted: the
+    /// mappings must not overlap, neither in their sources nor in their destinations, and the
+    /// destinations must cover exactly the sources, so the unmapped values stay in place both ways.
+    fn invert(self: &AdventMap) -> Result<AdventMap, String> {
+        let sources = merge_ranges(self.mappings.iter().map(|m| Range {
+            start: m.start,
+            end: m.end,
+        }));
+        let destinations = merge_ranges(self.mappings.iter().map(|m| Range {
+            start: m.start + m.shift,
+            end: m.end + m.shift,
+        }));
+        match (sources, destinations) {
+            (Some(sources), Some(destinations)) if sources == destinations => Ok(AdventMap {
+                source: self.destination.clone(),
+                destination: self.source.clone(),
+                mappings: self
+                    .mappings
+                    .iter()
+                    .map(|m| Mapping {
+                        start: m.start + m.shift,
+                        end: m.end + m.shift,
+                        shift: -m.shift,
+                    })
+                    .collect(),
+                problems: vec![],
+            }),
+            _ => Err(format!(
+                "The map from {} to {} is not a bijection and cannot be inverted",
+                self.source, self.destination
+            )),
+        }
+    }
+}
+
+/// Reads the source and destination category of a header like `seed-to-soil map:`
+fn parse_header(line: &str) -> Option<(String, String)> {
+    let (source, destination) = line.strip_suffix(" map:")?.split_once("-to-")?;
+    Some((source.to_string(), destination.to_string()))
+}
+
+fn get_seeds(seed_line: &str) -> Result<Vec<i64>, String> {
+    let (_, seeds) = seed_line
+        .split_once(":")
+        .ok_or(format!("line 1: cannot parse seeds {}", seed_line))?;
+    Ok(seeds
+        .split(" ")
+        .filter_map(|x| x.parse::<i64>().ok())
+        .collect())
+}
+
+/// Parses all maps after the seeds line, in the order of the input
+fn parse_input_to_maps(input: &[String]) -> Result<Vec<AdventMap>, String> {
+    let mut maps: Vec<AdventMap> = vec![];
+    for (line_number, line) in input.iter().enumerate().skip(1) {
+        if line.is_empty() {
+            continue;
+        }
+        if line.contains(":") {
+            let (source, destination) = parse_header(line).ok_or(format!(
+                "line {}: cannot parse map header {}",
+                line_number + 1,
+                line
+            ))?;
+            maps.push(AdventMap::new(&source, &destination));
+        } else {
+            maps.last_mut()
+                .ok_or(format!(
+                    "line {}: mapping before the first map header",
+                    line_number + 1
+                ))?
+                .parse_and_insert(line_number + 1, line)?;
+        }
+    }
+    Ok(maps)
+}
+
+/// All pairs of overlapping ranges, given as (start, end, mapping)
+fn find_overlaps(ranges: impl Iterator<Item = (i64, i64, Mapping)>) -> Vec<(Mapping, Mapping)> {
+    let mut ranges = ranges.collect::<Vec<(i64, i64, Mapping)>>();
+    ranges.sort();
+    let mut overlaps = vec![];
+    for (index, (_, end, mapping)) in ranges.iter().enumerate() {
+        for (start, _, other) in ranges[index + 1..].iter() {
+            if start > end {
+                break;
+            }
+            overlaps.push((*mapping, *other));
+        }
+    }
+    overlaps
+}
+
+/// Sorts the ranges and merges touching ones, None if two ranges overlap
+fn merge_ranges(ranges: impl Iterator<Item = Range>) -> Option<Vec<Range>> {
+    let mut ranges = ranges.collect::<Vec<Range>>();
+    ranges.sort();
+    let mut merged: Vec<Range> = vec![];
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => return None,
+            Some(last) if range.start == last.end + 1 => last.end = range.end,
+            _ => merged.push(range),
+        }
+    }
+    Some(merged)
+}
+
+/// A composed map only changes its shift at its breakpoints, so the lowest location of a seed range
+/// is found at the start of the range or at one of the breakpoints inside it
+fn lowest_location(map: &AdventMap, ranges: &[Range]) -> Result<i64, String> {
+    let breakpoints = map.breakpoints();
+    ranges
+        .iter()
+        .flat_map(|r| {
+            [r.start]
+                .into_iter()
+                .chain(breakpoints.range(r.start..=r.end).copied())
+        })
+        .map(|x| map.apply(x))
+        .min()
+        .ok_or("There are no seed ranges".to_string())
+}
+
+/// Reads the seeds as pairs of start and length, every range must hold at least one seed
+fn seeds_to_ranges(seeds: &[i64]) -> Result<Vec<Range>, String> {
+    if !seeds.len().is_multiple_of(2) {
+        return Err(format!(
+            "The seed ranges need pairs of start and length, there are {} numbers",
+            seeds.len()
+        ));
+    }
+    seeds
+        .chunks_exact(2)
+        .map(|x| match x[0].checked_add(x[1] - 1) {
+            Some(end) if x[1] >= 1 => Ok(Range { start: x[0], end }),
+            _ => Err(format!("The seed range {} {} is invalid", x[0], x[1])),
+        })
+        .collect()
+}
+
+/// The parsed almanac, which can be explored in the REPL
+pub struct Almanac {
+    seeds: Vec<i64>,
+    maps: Vec<AdventMap>,
+    /// The categories form a graph with the maps as edges, every category has at most one map to another
+    maps_by_source: BTreeMap<String, usize>,
+}
+
+impl Almanac {
+    pub fn parse(input: &[String]) -> Result<Almanac, String> {
+        let seed_line = input.first().ok_or("The almanac is empty")?;
+        let maps = parse_input_to_maps(input)?;
+        let mut maps_by_source = BTreeMap::new();
+        for (index, map) in maps.iter().enumerate() {
+            if maps_by_source.insert(map.source.clone(), index).is_some() {
+                return Err(format!("There are several maps from {}", map.source));
+            }
+        }
+        Ok(Almanac {
+            seeds: get_seeds(seed_line)?,
+            maps,
+            maps_by_source,
+        })
+    }
+
+    /// The maps leading from one category to another, fails if the chain has a gap or runs in a cycle
+    fn chain(self: &Almanac, from: &str, to: &str) -> Result<Vec<&AdventMap>, String> {
+        let mut chain = vec![];
+        let mut visited = BTreeSet::from([from]);
+        let mut category = from;
+        while category != to {
+            let map = match self.maps_by_source.get(category) {
+                Some(index) => &self.maps[*index],
+                None => {
+                    return Err(format!(
+                        "There is no map from {} on the way from {} to {}",
+                        category, from, to
+                    ))
+                }
+            };
+            category = &map.destination;
+            if !visited.insert(category) {
+                return Err(format!(
+                    "The maps from {} run in a cycle through {} without reaching {}",
+                    from, category, to
+                ));
+            }
+            chain.push(map);
+        }
+        Ok(chain)
+    }
+
+    /// All problems of all maps, prefixed with the name of the map
+    fn validate(self: &Almanac) -> Vec<(String, Problem)> {
+        self.maps
+            .iter()
+            .flat_map(|map| {
+                let name = format!("{}-to-{}", map.source, map.destination);
+                map.validate().into_iter().map(move |p| (name.clone(), p))
+            })
+            .collect()
+    }
+
+    /// The maps from one category to another composed into a single map
+    fn composed(self: &Almanac, from: &str, to: &str) -> Result<AdventMap, String> {
+        Ok(self
+            .chain(from, to)?
+            .into_iter()
+            .fold(AdventMap::new(from, from), |composed, map| {
+                composed.compose(map)
+            }))
+    }
+
+    /// The value after each map of the chain from one category to another, starting with the value itself
+    fn trace(self: &Almanac, value: i64, from: &str, to: &str) -> Result<Vec<i64>, String> {
+        let mut values = vec![value];
+        for map in self.chain(from, to)? {
+            values.push(map.apply(*values.last().unwrap()));
+        }
+        Ok(values)
+    }
+}
+
+fn format_map(map: &AdventMap) -> String {
+    let mappings = map
+        .mappings
+        .iter()
+        .map(|m| format!("[{}, {}] {:+}", m.start, m.end, m.shift))
+        .collect::<Vec<String>>();
+    format!(
+        "{}-to-{}: {}",
+        map.source,
+        map.destination,
+        mappings.join(", ")
+    )
+}
+
+impl Explore for Almanac {
+    fn help(self: &Almanac) -> Vec<&'static str> {
+        vec![
+            "seeds                      lists the seeds",
+            "maps                       lists the mappings of all maps as [start, end] +shift",
+            "normalized                 lists the maps without overlaps and with the gaps filled",
+            "validate                   lists overlapping, empty and overflowing mappings",
+            "map <value>                applies the map chain from seed to location to a value",
+            "convert <value> <from> <to> applies the map chain from one category to another to a value",
+            "reverse <value> <from> <to> finds the value of category from that ends up as value of category to",
+        ]
+    }
+
+    fn query(self: &Almanac, command: &str, arguments: &[&str]) -> Option<String> {
+        let output = match command {
+            "seeds" => self
+                .seeds
+                .iter()
+                .map(|s| s.to_string())
+                .collect::<Vec<_>>()
+                .join(" "),
+            "maps" => self
+                .maps
+                .iter()
+                .map(format_map)
+                .collect::<Vec<String>>()
+                .join("\n"),
+            "normalized" => self
+                .maps
+                .iter()
+                .map(|map| format_map(&map.normalize()))
+                .collect::<Vec<String>>()
+                .join("\n"),
+            "validate" => {
+                let problems = self.validate();
+                if problems.is_empty() {
+                    "All maps are valid".to_string()
+                } else {
+                    problems
+                        .iter()
+                        .map(|(name, problem)| format!("{}: {}", name, problem))
+                        .collect::<Vec<String>>()
+                        .join("\n")
+                }
+            }
+            "map" | "convert" => {
+                let value = arguments.first().and_then(|v| v.parse::<i64>().ok());
+                let (from, to) = match command {
+                    "map" => (Some(&"seed"), Some(&"location")),
+                    _ => (arguments.get(1), arguments.get(2)),
+                };
+                match (value, from, to) {
+                    (Some(value), Some(from), Some(to)) => match self.trace(value, from, to) {
+                        Ok(values) => values
+                            .iter()
+                            .map(|v| v.to_string())
+                            .collect::<Vec<String>>()
+                            .join(" -> "),
+                        Err(message) => message,
+                    },
+                    (None, _, _) => "Please specify the value to map".to_string(),
+                    _ => "Please specify the categories to map from and to".to_string(),
+                }
+            }
+            "reverse" => match (
+                arguments.first().and_then(|v| v.parse::<i64>().ok()),
+                arguments.get(1),
+                arguments.get(2),
+            ) {
+                (Some(value), Some(from), Some(to)) => {
+                    match self.composed(from, to).and_then(|m| m.invert()) {
+                        Ok(inverse) => inverse.apply(value).to_string(),
+                        Err(message) => message,
+                    }
+                }
+                _ => "Please specify the value and the categories to map from and to".to_string(),
+            },
+            _ => return None,
+        };
+        Some(output)
+    }
+}
+
+/// The seeds with the composed map from seeds to locations, ready to solve both parts
+pub struct SeedLocations {
+    seeds: Vec<i64>,
+    map: AdventMap,
+}
+
+impl Almanac {
+    /// Composes the maps from seeds to locations, fails on problems that make the maps ambiguous
+    pub fn seed_locations(self: &Almanac) -> Result<SeedLocations, String> {
+        let problems = self
+            .validate()
+            .into_iter()
+            .filter(|(_, problem)| problem.is_fatal())
+            .map(|(name, problem)| format!("{}: {}", name, problem))
+            .collect::<Vec<String>>();
+        if !problems.is_empty() {
+            return Err(problems.join("\n"));
+        }
+        Ok(SeedLocations {
+            seeds: self.seeds.clone(),
+            map: self.composed("seed", "location")?,
+        })
+    }
+}
+
+impl SeedLocations {
+    fn lowest_location_of_seeds(self: &SeedLocations) -> Result<u64, String> {
+        self.seeds
+            .iter()
+            .map(|s| self.map.apply(*s))
+            .min()
+            .map(|location| location as u64)
+            .ok_or("There are no seeds".to_string())
+    }
+
+    fn lowest_location_of_ranges(self: &SeedLocations) -> Result<u64, String> {
+        Ok(lowest_location(&self.map, &seeds_to_ranges(&self.seeds)?)? as u64)
+    }
+}
+
+impl Parts for SeedLocations {
+    fn part_1(&self) -> Result<String, String> {
+        Ok(self.lowest_location_of_seeds()?.to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        Ok(self.lowest_location_of_ranges()?.to_string())
+    }
+}
+
+/// Maps the seeds to locations, following the categories of the map headers. The maps are
+/// composed into a single map first. Fails for maps with overlapping sources, empty or
+/// overflowing entries, as their result would depend on the order of the entries.
+pub fn solve(input: &[String]) -> Result<(u64, u64), String> {
+    let seed_locations = Almanac::parse(input)?.seed_locations()?;
+    Ok((
+        seed_locations.lowest_location_of_seeds()?,
+        seed_locations.lowest_location_of_ranges()?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    extern crate test;
+    use test::Bencher;
+
+    #[test]
+    fn test_day5() {
+        let result_1 = 35;
+        let result_2 = 46;
+        let input = vec![
+            "seeds: 79 14 55 13".to_string(),
+            "".to_string(),
+            "seed-to-soil map:".to_string(),
+            "50 98 2".to_string(),
+            "52 50 48".to_string(),
+            "".to_string(),
+            "soil-to-fertilizer map:".to_string(),
+            "0 15 37".to_string(),
+            "37 52 2".to_string(),
+            "39 0 15".to_string(),
+            "".to_string(),
+            "fertilizer-to-water map:".to_string(),
+            "49 53 8".to_string(),
+            "0 11 42".to_string(),
+            "42 0 7".to_string(),
+            "57 7 4".to_string(),
+            "".to_string(),
+            "water-to-light map:".to_string(),
+            "88 18 7".to_string(),
+            "18 25 70".to_string(),
+            "".to_string(),
+            "light-to-temperature map:".to_string(),
+            "45 77 23".to_string(),
+            "81 45 19".to_string(),
+            "68 64 13".to_string(),
+            "".to_string(),
+            "temperature-to-humidity map:".to_string(),
+            "0 69 1".to_string(),
+            "1 0 69".to_string(),
+            "".to_string(),
+            "humidity-to-location map:".to_string(),
+            "60 56 37".to_string(),
+            "56 93 4".to_string(),
+        ];
+        let (output_1, output_2) = solve(&input).unwrap();
+        assert_eq!(result_1, output_1);
+        assert_eq!(result_2, output_2);
+
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(
+            Some("79 -> 81 -> 81 -> 81 -> 74 -> 78 -> 78 -> 82".to_string()),
+            almanac.query("map", &["79"])
+        );
+        assert_eq!(
+            Some("81 -> 81 -> 74".to_string()),
+            almanac.query("convert", &["81", "fertilizer", "light"])
+        );
+        assert_eq!(
+            Some("79".to_string()),
+            almanac.query("reverse", &["82", "seed", "location"])
+        );
+    }
+
+    #[test]
+    fn test_day5_composition() {
+        let input = [
+            "seeds: 79 14 55 13",
+            "seed-to-soil map:",
+            "50 98 2",
+            "52 50 48",
+            "soil-to-fertilizer map:",
+            "0 15 37",
+            "37 52 2",
+            "39 0 15",
+            "fertilizer-to-water map:",
+            "49 53 8",
+            "0 11 42",
+            "42 0 7",
+            "57 7 4",
+        ]
+        .map(|l| l.to_string())
+        .to_vec();
+        let almanac = Almanac::parse(&input).unwrap();
+        let composed = almanac.composed("seed", "water").unwrap();
+        let inverse = composed.invert().unwrap();
+        for value in -5..120 {
+            let trace = almanac.trace(value, "seed", "water").unwrap();
+            assert_eq!(*trace.last().unwrap(), composed.apply(value));
+            assert_eq!(value, inverse.apply(composed.apply(value)));
+        }
+
+        let mut not_injective = AdventMap::new("a", "b");
+        not_injective.parse_and_insert(1, "10 0 5").unwrap();
+        assert!(not_injective.invert().is_err());
+    }
+
+    #[test]
+    fn test_day5_validation() {
+        let input = [
+            "seeds: 79 14",
+            "seed-to-location map:",
+            "50 10 5",
+            "52 12 5",
+            "7 30 0",
+            "0 9223372036854775800 10",
+            "0 9223372036854775798 10",
+        ]
+        .map(|l| l.to_string())
+        .to_vec();
+        assert_eq!(
+            Err([
+                "seed-to-location: line 5: entry of length 0 maps nothing",
+                "seed-to-location: line 6: entry overflows",
+                "seed-to-location: line 7: entry overflows",
+                "seed-to-location: sources of [10, 14] +40 and [12, 16] +40 overlap",
+            ]
+            .join("\n")),
+            solve(&input)
+        );
+
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(
+            "seed-to-location: destinations of [10, 14] +40 and [12, 16] +40 overlap",
+            almanac
+                .query("validate", &[])
+                .unwrap()
+                .lines()
+                .last()
+                .unwrap()
+        );
+        assert_eq!(
+            Some("seed-to-location: [0, 9] +0, [10, 16] +40".to_string()),
+            almanac.query("normalized", &[])
+        );
+    }
+
+    #[test]
+    fn test_day5_categories() {
+        let input = [
+            "seeds: 1 2",
+            "",
+            "soil-to-location map:",
+            "100 0 10",
+            "",
+            "seed-to-soil map:",
+            "5 0 10",
+        ]
+        .map(|l| l.to_string())
+        .to_vec();
+        assert_eq!(Ok((106, 106)), solve(&input));
+        let almanac = Almanac::parse(&input).unwrap();
+        assert_eq!(
+            Err("There is no map from location on the way from soil to water".to_string()),
+            almanac.trace(1, "soil", "water")
+        );
+
+        let mut cycle = input.clone();
+        cycle[2] = "soil-to-seed map:".to_string();
+        assert_eq!(
+            Err(
+                "The maps from seed run in a cycle through seed without reaching location"
+                    .to_string()
+            ),
+            solve(&cycle)
+        );
+
+        assert_eq!(Err("The almanac is empty".to_string()), solve(&[]));
+        assert_eq!(
+            Err("line 1: cannot parse seeds 79 14".to_string()),
+            solve(&["79 14".to_string()])
+        );
+
+        let with_seeds = |line: &str| {
+            let mut seeds = input.clone();
+            seeds[0] = line.to_string();
+            seeds
+        };
+        let seeds = with_seeds("seeds:");
+        assert_eq!(Err("There are no seeds".to_string()), solve(&seeds));
+        let seed_locations = Almanac::parse(&seeds).unwrap().seed_locations().unwrap();
+        assert_eq!(
+            Err("There are no seeds".to_string()),
+            seed_locations.part_1()
+        );
+        assert_eq!(
+            Err("There are no seed ranges".to_string()),
+            seed_locations.part_2()
+        );
+
+        let seeds = with_seeds("seeds: 1 2 3");
+        let odd_count =
+            "The seed ranges need pairs of start and length, there are 3 numbers".to_string();
+        assert_eq!(Err(odd_count.clone()), solve(&seeds));
+        let seed_locations = Almanac::parse(&seeds).unwrap().seed_locations().unwrap();
+        assert_eq!(Ok("106".to_string()), seed_locations.part_1());
+        assert_eq!(Err(odd_count), seed_locations.part_2());
+
+        assert_eq!(
+            Err("The seed range 1 0 is invalid".to_string()),
+            solve(&with_seeds("seeds: 1 0"))
+        );
+    }
+
+    #[bench]
+    fn bench_day5_part_1(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day5.txt");
+        b.iter(|| {
+            let almanac = Almanac::parse(&input).unwrap();
+            let map = almanac.composed("seed", "location").unwrap();
+            let _result = almanac.seeds.iter().map(|s| map.apply(*s)).min();
+        });
+    }
+
+    #[bench]
+    fn bench_day5_part_2(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day5.txt");
+        b.iter(|| {
+            let almanac = Almanac::parse(&input).unwrap();
+            let map = almanac.composed("seed", "location").unwrap();
+            let _min_value = lowest_location(&map, &seeds_to_ranges(&almanac.seeds).unwrap());
+        });
+    }
+}
@@ -0,0 +1,523 @@
+use num::integer::Roots;
+use num::{BigUint, Integer};
+
+use crate::options::Options;
+use crate::registry::Parts;
+
+#[derive(Debug)]
+struct Race {
+    time: u64,
+    distance: u64,
+}
+
+/// How the speed grows with the time the button is held
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Acceleration {
+    /// Speed = charge rate * hold time
+    Linear,
+    /// Speed = charge rate * hold time^2
+    Quadratic,
+}
+
+/// The physics of the boat, by default the one of the puzzle: one unit of speed per millisecond
+/// held, without limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct BoatModel {
+    charge_rate: u64,
+    max_speed: Option<u64>,
+    acceleration: Acceleration,
+}
+
+impl Default for BoatModel {
+    fn default() -> BoatModel {
+        BoatModel {
+            charge_rate: 1,
+            max_speed: None,
+            acceleration: Acceleration::Linear,
+        }
+    }
+}
+
+impl BoatModel {
+    /// Reads `--charge-rate`, `--max-speed` and `--acceleration`
+    fn from_options(options: &Options) -> Result<BoatModel, String> {
+        let mut model = BoatModel::default();
+        if let Some(rate) = options.get("charge-rate") {
+            model.charge_rate = rate
+                .parse()
+                .ok()
+                .filter(|r| *r > 0)
+                .ok_or(format!("Charge rate must be a positive number: {}", rate))?;
+        }
+        if let Some(speed) = options.get("max-speed") {
+            model.max_speed = Some(
+                speed
+                    .parse()
+                    .map_err(|_| format!("Maximum speed must be a number: {}", speed))?,
+            );
+        }
+        model.acceleration = match options.get("acceleration") {
+            None | Some("linear") => Acceleration::Linear,
+            Some("quadratic") => Acceleration::Quadratic,
+            Some(other) => return Err(format!("Unknown acceleration {}", other)),
+        };
+        Ok(model)
+    }
+
+    /// Without speed limit the linear model is a quadratic equation we can solve directly
+    fn is_analytic(self: &BoatModel) -> bool {
+        self.acceleration == Acceleration::Linear && self.max_speed.is_none()
+    }
+
+    /// Distance travelled in a race of the given time when holding the button for hold_time.
+    /// Huge results saturate at u128::MAX.
+    fn distance(self: &BoatModel, time: u64, hold_time: u64) -> u128 {
+        let hold_time = hold_time as u128;
+        let speed = match self.acceleration {
+            Acceleration::Linear => hold_time.saturating_mul(self.charge_rate as u128),
+            Acceleration::Quadratic => hold_time
+                .saturating_mul(hold_time)
+                .saturating_mul(self.charge_rate as u128),
+        };
+        let speed = match self.max_speed {
+            Some(max_speed) => speed.min(max_speed as u128),
+            None => speed,
+        };
+        speed.saturating_mul(time as u128 - hold_time)
+    }
+
+    /// The shortest hold time giving the longest distance. All models first gain and then lose
+    /// distance with longer hold times, so we can search for the point where the gain stops.
+    fn optimal_hold_time(self: &BoatModel, time: u64) -> u64 {
+        if self.is_analytic() {
+            return time / 2;
+        }
+        partition_point(0, time, |h| {
+            self.distance(time, h + 1) > self.distance(time, h)
+        })
+    }
+
+    /// The first and last hold time beating the record, None if it can't be beaten
+    fn winning_hold_times(self: &BoatModel, race: &Race) -> Option<(u64, u64)> {
+        if self.is_analytic() {
+            // rate * h * (t - h) > d holds exactly when h * (t - h) > d / rate, rounded down
+            let interval = winning_interval(
+                race.time as u128,
+                (race.distance / self.charge_rate) as u128,
+            );
+            return interval.map(|(lower, upper)| (lower as u64, upper as u64));
+        }
+        let optimum = self.optimal_hold_time(race.time);
+        let record = race.distance as u128;
+        if self.distance(race.time, optimum) <= record {
+            return None;
+        }
+        // The distance grows up to the optimum and shrinks after it
+        let lower = partition_point(0, optimum, |h| self.distance(race.time, h) <= record);
+        let upper = partition_point(optimum, race.time + 1, |h| {
+            self.distance(race.time, h) > record
+        }) - 1;
+        Some((lower, upper))
+    }
+}
+
+/// The first value in lower..upper for which the condition is false, upper if it holds for all.
+/// The condition has to hold up to some value and not from there on.
+fn partition_point(mut lower: u64, mut upper: u64, condition: impl Fn(u64) -> bool) -> u64 {
+    while lower < upper {
+        let middle = lower + (upper - lower) / 2;
+        if condition(middle) {
+            lower = middle + 1;
+        } else {
+            upper = middle;
+        }
+    }
+    lower
+}
+
+impl Race {
+    /// The brute force solution, works reasonably well since rust is quite fast :-D
+    #[cfg(test)]
+    fn wins(self: &Race, model: &BoatModel, hold_time: u64) -> bool {
+        model.distance(self.time, hold_time) > self.distance as u128
+    }
+
+    #[cfg(test)]
+    fn number_of_beatings_brute_force(self: &Race, model: &BoatModel) -> u64 {
+        (0..self.time).filter(|t| self.wins(model, *t)).count() as u64
+    }
+
+    fn number_of_beatings(self: &Race, model: &BoatModel) -> u64 {
+        model
+            .winning_hold_times(self)
+            .map_or(0, |(lower, upper)| upper - lower + 1)
+    }
+}
+
+/// Using quradratic formula:
+/// d = distance, t = time to beat, h = hold time
+/// The time our boat races is time to beat minus hold time
+/// The distance it travels is the hold time times the time it races
+/// d = (t - h) * h = t * h - h^2
+/// this can be rearranged to a quadratic equation
+/// 0 = h^2 - h * t + d
+/// which can be solved using the quadratic formula
+/// h = (t +- sqrt(t^2 - 4 * d)) / 2
+/// We take the integer square root, so the bounds can be off by one and are corrected by checking
+/// their neighbors. All hold times between the bounds (including the bounds themselves) beat the record.
+fn winning_interval<T: Integer + Roots + Clone + From<u8>>(time: T, distance: T) -> Option<(T, T)> {
+    // We want to beat the other boat by at least one unit of distance
+    let target = distance + T::one();
+    let wins = |h: &T| h.clone() * (time.clone() - h.clone()) >= target;
+
+    let time_squared = time.clone() * time.clone();
+    let four_target = T::from(4) * target.clone();
+    if time_squared < four_target {
+        return None;
+    }
+    let root = (time_squared - four_target).sqrt();
+
+    let mut lower = (time.clone() - root.clone()) / T::from(2);
+    while !wins(&lower) {
+        lower = lower + T::one();
+    }
+    while lower > T::zero() && wins(&(lower.clone() - T::one())) {
+        lower = lower - T::one();
+    }
+    let mut upper = ((time.clone() + root) / T::from(2)).min(time.clone());
+    while !wins(&upper) {
+        upper = upper - T::one();
+    }
+    while upper < time && wins(&(upper.clone() + T::one())) {
+        upper = upper + T::one();
+    }
+    Some((lower, upper))
+}
+
+/// Every time needs a distance, the numbers of the times are on line 1, those of the distances on line 2
+fn parse_races(times: &str, distances: &str) -> Result<Vec<Race>, String> {
+    let parse_numbers = |line_number: usize, numbers: &str| {
+        numbers
+            .split_whitespace()
+            .map(|n| {
+                n.parse::<u64>()
+                    .map_err(|_| format!("line {}: cannot parse {}", line_number, n))
+            })
+            .collect::<Result<Vec<u64>, String>>()
+    };
+    let times = parse_numbers(1, times)?;
+    let distances = parse_numbers(2, distances)?;
+    if times.len() != distances.len() {
+        return Err(format!(
+            "There are {} times but {} distances",
+            times.len(),
+            distances.len()
+        ));
+    }
+
+    Ok(times
+        .into_iter()
+        .zip(distances)
+        .map(|(time, distance)| Race { time, distance })
+        .collect())
+}
+
+/// The numbers of the long race are the kerned numbers of all races, if they don't fit into u64 we
+/// count with big integers, which is only possible for the analytic model
+fn long_race_beatings(times: &str, distances: &str, model: &BoatModel) -> Result<BigUint, String> {
+    let time = times.replace(" ", "");
+    let distance = distances.replace(" ", "");
+    match (time.parse::<u64>(), distance.parse::<u64>()) {
+        (Ok(time), Ok(distance)) => Ok(BigUint::from(
+            Race { time, distance }.number_of_beatings(model),
+        )),
+        _ if model.is_analytic() => {
+            let time = time.parse::<BigUint>().expect("Cannot parse time");
+            let distance = distance.parse::<BigUint>().expect("Cannot parse distance")
+                / BigUint::from(model.charge_rate);
+            Ok(
+                winning_interval(time, distance).map_or(BigUint::from(0u32), |(lower, upper)| {
+                    upper - lower + BigUint::from(1u32)
+                }),
+            )
+        }
+        _ => Err(format!(
+            "The long race {} / {} is too long for this boat model",
+            time, distance
+        )),
+    }
+}
+
+fn describe_race(index: usize, race: &Race, model: &BoatModel) -> String {
+    let optimum = model.optimal_hold_time(race.time);
+    let wins = match model.winning_hold_times(race) {
+        Some((lower, upper)) => format!("wins holding {} to {} ms", lower, upper),
+        None => "cannot win".to_string(),
+    };
+    format!(
+        "race {}: best hold time {} ms goes {} against the record {}, {}",
+        index,
+        optimum,
+        model.distance(race.time, optimum),
+        race.distance,
+        wins
+    )
+}
+
+/// The races of the sheet, the numbers are kept as text for the long race of part 2
+pub struct RaceSheet {
+    races: Vec<Race>,
+    times: String,
+    distances: String,
+    model: BoatModel,
+}
+
+impl RaceSheet {
+    /// Uses the boat of the puzzle unless `--charge-rate`, `--max-speed` or `--acceleration` are given
+    pub fn parse(input: &[String], options: &Options) -> Result<RaceSheet, String> {
+        let model = BoatModel::from_options(options)?;
+        let numbers = |index: usize, name: &str| {
+            input
+                .get(index)
+                .and_then(|line| line.split(':').nth(1))
+                .map(|numbers| numbers.trim())
+                .ok_or_else(|| format!("line {}: expected the {} of the races", index + 1, name))
+        };
+        let times = numbers(0, "times")?;
+        let distances = numbers(1, "distances")?;
+        Ok(RaceSheet {
+            races: parse_races(times, distances)?,
+            times: times.to_string(),
+            distances: distances.to_string(),
+            model,
+        })
+    }
+
+    fn winnings_product(self: &RaceSheet) -> u64 {
+        self.races
+            .iter()
+            .map(|r| r.number_of_beatings(&self.model))
+            .product()
+    }
+}
+
+impl Parts for RaceSheet {
+    fn part_1(&self) -> Result<String, String> {
+        Ok(self.winnings_product().to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        long_race_beatings(&self.times, &self.distances, &self.model).map(|b| b.to_string())
+    }
+}
+
+/// With `--optimal` the best hold time of every race is reported.
+pub fn solve(input: &[String], options: &Options) -> Result<((u64, BigUint), Vec<String>), String> {
+    let sheet = RaceSheet::parse(input, options)?;
+
+    let report = if options.is_set("optimal") {
+        sheet
+            .races
+            .iter()
+            .enumerate()
+            .map(|(index, race)| describe_race(index + 1, race, &sheet.model))
+            .collect()
+    } else {
+        vec![]
+    };
+
+    Ok((
+        (
+            sheet.winnings_product(),
+            long_race_beatings(&sheet.times, &sheet.distances, &sheet.model)?,
+        ),
+        report,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    extern crate test;
+    use test::Bencher;
+
+    #[test]
+    fn test_day6() {
+        let result_1 = 288;
+        let result_2 = 71503;
+        let input = vec![
+            "Time:      7  15   30".to_string(),
+            "Distance:  9  40  200".to_string(),
+        ];
+        let ((output_1, output_2), report) = solve(&input, &Options::default()).unwrap();
+        assert_eq!(result_1, output_1);
+        assert_eq!(BigUint::from(result_2 as u32), output_2);
+        assert!(report.is_empty());
+
+        let (_, options) = Options::parse(&["--optimal".to_string()]);
+        let (_, report) = solve(&input, &options).unwrap();
+        assert_eq!(3, report.len());
+        assert_eq!(
+            describe_race(
+                1,
+                &Race {
+                    time: 7,
+                    distance: 9
+                },
+                &BoatModel::default()
+            ),
+            report[0]
+        );
+
+        let invalid = |input: &[&str]| {
+            let input = input.iter().map(|l| l.to_string()).collect::<Vec<String>>();
+            RaceSheet::parse(&input, &Options::default()).map(|_| ())
+        };
+        assert_eq!(
+            Err("line 1: expected the times of the races".to_string()),
+            invalid(&[])
+        );
+        assert_eq!(
+            Err("line 2: expected the distances of the races".to_string()),
+            invalid(&["Time: 7 15"])
+        );
+        assert_eq!(
+            Err("line 2: cannot parse 4x".to_string()),
+            invalid(&["Time: 7 15", "Distance: 9 4x"])
+        );
+        assert_eq!(
+            Err("There are 2 times but 1 distances".to_string()),
+            invalid(&["Time: 7 15", "Distance: 9"])
+        );
+    }
+
+    #[test]
+    fn test_day6_exact() {
+        // Even and odd times, compared to the brute force
+        for time in [0, 1, 2, 3, 4, 7, 15, 30, 71, 100, 299] {
+            let best = (time / 2) * (time - time / 2);
+            // Distances around the best possible one are the critical cases
+            for offset in [0, 1, 2, time / 2, time, time + 1] {
+                let race = Race {
+                    time,
+                    distance: best.saturating_sub(offset),
+                };
+                let model = BoatModel::default();
+                assert_eq!(
+                    race.number_of_beatings_brute_force(&model),
+                    race.number_of_beatings(&model)
+                );
+            }
+        }
+
+        // Far beyond the precision of f64: the record is held for h0, so all h0 < h < t - h0 win
+        let time = (1u64 << 62) + 1;
+        let h0 = 3u64;
+        let race = Race {
+            time,
+            distance: h0 * (time - h0),
+        };
+        assert_eq!(
+            time - 2 * h0 - 1,
+            race.number_of_beatings(&BoatModel::default())
+        );
+
+        let time = BigUint::from(10u32).pow(30);
+        let h0 = BigUint::from(10u32).pow(12) + BigUint::from(7u32);
+        let distance = h0.clone() * (time.clone() - h0.clone());
+        let expected = time.clone() - BigUint::from(2u32) * h0 - BigUint::from(1u32);
+        assert_eq!(
+            expected,
+            long_race_beatings(
+                &time.to_string(),
+                &distance.to_string(),
+                &BoatModel::default()
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_day6_models() {
+        let models = [
+            BoatModel {
+                charge_rate: 3,
+                ..Default::default()
+            },
+            BoatModel {
+                charge_rate: 2,
+                max_speed: Some(25),
+                acceleration: Acceleration::Linear,
+            },
+            BoatModel {
+                charge_rate: 1,
+                max_speed: None,
+                acceleration: Acceleration::Quadratic,
+            },
+            BoatModel {
+                charge_rate: 2,
+                max_speed: Some(300),
+                acceleration: Acceleration::Quadratic,
+            },
+        ];
+        for model in models.iter() {
+            for time in 0..60 {
+                let best = (0..=time).map(|h| model.distance(time, h)).max().unwrap();
+                let optimum = model.optimal_hold_time(time);
+                assert_eq!(best, model.distance(time, optimum));
+                for distance in [
+                    0,
+                    best as u64 / 2,
+                    (best as u64).saturating_sub(1),
+                    best as u64,
+                ] {
+                    let race = Race { time, distance };
+                    assert_eq!(
+                        race.number_of_beatings_brute_force(model),
+                        race.number_of_beatings(model)
+                    );
+                }
+            }
+        }
+
+        let (_, options) = Options::parse(&["--acceleration=quadratic".to_string()]);
+        assert_eq!(
+            "race 1: best hold time 5 ms goes 50 against the record 9, wins holding 2 to 6 ms",
+            describe_race(
+                1,
+                &Race {
+                    time: 7,
+                    distance: 9
+                },
+                &BoatModel::from_options(&options).unwrap()
+            )
+        );
+    }
+
+    #[bench]
+    fn bench_day6_part_1(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day6.txt");
+        b.iter(|| {
+            let times = input[0].split(":").nth(1).unwrap().trim();
+            let distances = input[1].split(":").nth(1).unwrap().trim();
+            let races = parse_races(times, distances).unwrap();
+
+            races
+                .iter()
+                .map(|r| r.number_of_beatings(&BoatModel::default()))
+                .product::<u64>()
+        });
+    }
+
+    #[bench]
+    fn bench_day6_part_2(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day6.txt");
+        b.iter(|| {
+            let times = input[0].split(":").nth(1).unwrap().trim();
+            let distances = input[1].split(":").nth(1).unwrap().trim();
+
+            long_race_beatings(times, distances, &BoatModel::default())
+        });
+    }
+}
@@ -0,0 +1,531 @@
+use std::collections::{BTreeSet, HashMap};
+
+use itertools::Itertools;
+
+use crate::options::Options;
+use crate::registry::Parts;
+
+/// The rules of a Camel Cards variant: the ranks from weakest to strongest and the ranks that are
+/// wild. New variants only need a new rule set.
+#[derive(Debug, Clone)]
+struct RuleSet {
+    order: Vec<char>,
+    wild: BTreeSet<char>,
+}
+
+impl RuleSet {
+    fn new(order: &str, wild: &str) -> Result<RuleSet, String> {
+        let order = order.chars().collect::<Vec<char>>();
+        if order.is_empty() {
+            return Err("no ranks given".to_string());
+        }
+        if let Some(rank) = order
+            .iter()
+            .enumerate()
+            .find_map(|(i, rank)| order[..i].contains(rank).then_some(rank))
+        {
+            return Err(format!("rank '{}' appears twice", rank));
+        }
+        let wild = wild.chars().collect::<BTreeSet<char>>();
+        if let Some(rank) = wild.iter().find(|rank| !order.contains(rank)) {
+            return Err(format!("wild rank '{}' is not a rank", rank));
+        }
+        Ok(RuleSet { order, wild })
+    }
+
+    /// The rules of part 1
+    fn standard() -> RuleSet {
+        RuleSet::new("23456789TJQKA", "").unwrap()
+    }
+
+    /// The rules of part 2, J is a joker and the weakest card
+    fn jokers() -> RuleSet {
+        RuleSet::new("J23456789TQKA", "J").unwrap()
+    }
+
+    /// Strength of a single card, starting with 0 for the weakest
+    fn rank(self: &RuleSet, card: char) -> Option<usize> {
+        self.order.iter().position(|rank| *rank == card)
+    }
+}
+
+/// The type of a hand is the sizes of its groups of equal cards, largest first. Comparing the
+/// patterns lexicographically gives the usual order for any hand size, e.g. for five cards
+/// [5] > [4, 1] > [3, 2] > [3, 1, 1] > [2, 2, 1] > [2, 1, 1, 1] > [1, 1, 1, 1, 1].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct HandType(Vec<usize>);
+
+impl HandType {
+    /// Classifies the cards, wild cards always do best by joining the largest group
+    fn classify(cards: &[char], rules: &RuleSet) -> HandType {
+        let mut card_map = HashMap::new();
+        let mut wild_count = 0;
+        for card in cards {
+            if rules.wild.contains(card) {
+                wild_count += 1;
+            } else {
+                *card_map.entry(*card).or_insert(0) += 1;
+            }
+        }
+        let mut groups = card_map.into_values().collect::<Vec<usize>>();
+        groups.sort_by(|a, b| b.cmp(a));
+        match groups.first_mut() {
+            Some(largest) => *largest += wild_count,
+            None => groups.push(wild_count),
+        }
+        HandType(groups)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct Hand {
+    /// Type first, then the ranks of the cards in the order they were dealt
+    key: (HandType, Vec<usize>),
+}
+
+impl Hand {
+    fn new(cards: &[char], rules: &RuleSet) -> Result<Hand, String> {
+        let ranks = cards
+            .iter()
+            .map(|card| {
+                rules
+                    .rank(*card)
+                    .ok_or_else(|| format!("invalid card '{}'", card))
+            })
+            .collect::<Result<Vec<usize>, String>>()?;
+        Ok(Hand {
+            key: (HandType::classify(cards, rules), ranks),
+        })
+    }
+}
+
+/// Parses one hand and its bet per line, the cards are parsed by `parse_cards`
+fn parse_bid_list<T>(
+    input: &[String],
+    mut parse_cards: impl FnMut(&str) -> Result<T, String>,
+) -> Result<Vec<(T, u64)>, String> {
+    let mut bids = vec![];
+    let mut errors = vec![];
+
+    for (line_number, line) in input.iter().enumerate() {
+        let mut hand_data = line.split_whitespace();
+        let bid = match (hand_data.next(), hand_data.next(), hand_data.next()) {
+            (Some(cards), Some(bet), None) => parse_cards(cards).and_then(|cards| {
+                let bet = bet
+                    .parse::<u64>()
+                    .map_err(|_| format!("invalid bet '{}'", bet))?;
+                Ok((cards, bet))
+            }),
+            _ => Err("expected cards and a bet".to_string()),
+        };
+        match bid {
+            Ok(bid) => bids.push(bid),
+            Err(message) => errors.push(format!("line {}: {}", line_number + 1, message)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(bids)
+    } else {
+        Err(errors.join("\n"))
+    }
+}
+
+/// Every hand wins its bet times its rank, hands of equal strength keep the order of the input
+fn total_winnings<T: Ord>(mut bids: Vec<(T, u64)>) -> u64 {
+    bids.sort_by(|(hand_a, _), (hand_b, _)| hand_a.cmp(hand_b));
+
+    bids.iter()
+        .enumerate()
+        .map(|(i, (_, bet))| bet * (i as u64 + 1))
+        .sum()
+}
+
+/// Parses one hand per line, all hands need the same number of cards to be comparable
+fn parse_hands(input: &[String], rules: &RuleSet) -> Result<Vec<(Vec<char>, u64)>, String> {
+    let mut hand_size = None;
+
+    parse_bid_list(input, |cards| {
+        let cards = cards.chars().collect::<Vec<char>>();
+        let size = *hand_size.get_or_insert(cards.len());
+        if cards.len() != size {
+            return Err(format!("{} cards instead of {}", cards.len(), size));
+        }
+        if let Some(card) = cards.iter().find(|card| rules.rank(**card).is_none()) {
+            return Err(format!("invalid card '{}'", card));
+        }
+        Ok(cards)
+    })
+}
+
+/// Total winnings of the hands ranked by the rules
+fn winnings(bids: &[(Vec<char>, u64)], rules: &RuleSet) -> Result<u64, String> {
+    let hands = bids
+        .iter()
+        .map(|(cards, bet)| Hand::new(cards, rules).map(|hand| (hand, *bet)))
+        .collect::<Result<Vec<(Hand, u64)>, String>>()?;
+    Ok(total_winnings(hands))
+}
+
+/// The suits of a poker deck: clubs, diamonds, hearts and spades
+const SUITS: &str = "cdhs";
+
+/// Size of a poker hand, straights and flushes need all of its cards
+const POKER_HAND_SIZE: usize = 5;
+
+/// A card of a poker deck, e.g. `Ah` for the ace of hearts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Card {
+    rank: usize,
+    suit: char,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum PokerCategory {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    Straight,
+    Flush,
+    FullHouse,
+    FourOfAKind,
+    StraightFlush,
+}
+
+/// Parses a hand of suited cards like `AhKd2c2s9h`, a hand cannot hold the same card twice
+fn parse_poker_hand(cards: &str, rules: &RuleSet) -> Result<Vec<Card>, String> {
+    let chars = cards.chars().collect::<Vec<char>>();
+    if chars.len() != 2 * POKER_HAND_SIZE {
+        return Err(format!("expected {} suited cards", POKER_HAND_SIZE));
+    }
+    let mut hand: Vec<Card> = vec![];
+    for card in chars.chunks_exact(2) {
+        let rank = rules
+            .rank(card[0])
+            .ok_or_else(|| format!("invalid rank '{}'", card[0]))?;
+        if !SUITS.contains(card[1]) {
+            return Err(format!("invalid suit '{}'", card[1]));
+        }
+        let card = Card {
+            rank,
+            suit: card[1],
+        };
+        if hand.contains(&card) {
+            return Err(format!("{}{} appears twice", rules.order[rank], card.suit));
+        }
+        hand.push(card);
+    }
+    Ok(hand)
+}
+
+/// Category and tie breakers of five ranks: the ranks ordered by the size of their group, then
+/// by rank, so pairs are compared before kickers. Straights only compare their highest card, the
+/// ace plays low in the wheel (A2345 with the usual ranks).
+fn evaluate(ranks: &[usize], flush: bool, rank_count: usize) -> (PokerCategory, Vec<usize>) {
+    let mut groups = ranks.iter().counts().into_iter().collect::<Vec<_>>();
+    groups.sort_by(|(rank_a, count_a), (rank_b, count_b)| {
+        count_b.cmp(count_a).then(rank_b.cmp(rank_a))
+    });
+    let pattern = groups
+        .iter()
+        .map(|(_, count)| *count)
+        .collect::<Vec<usize>>();
+    let tie_breakers = groups
+        .iter()
+        .map(|(rank, _)| **rank)
+        .collect::<Vec<usize>>();
+
+    let wheel = (0..POKER_HAND_SIZE - 1)
+        .chain([rank_count - 1])
+        .collect::<Vec<usize>>();
+    let straight_high = if pattern.len() != POKER_HAND_SIZE {
+        None
+    } else if tie_breakers[0] - tie_breakers[POKER_HAND_SIZE - 1] == POKER_HAND_SIZE - 1 {
+        Some(tie_breakers[0])
+    } else if tie_breakers.iter().all(|rank| wheel.contains(rank)) {
+        Some(POKER_HAND_SIZE - 2)
+    } else {
+        None
+    };
+
+    match (pattern.as_slice(), straight_high) {
+        (_, Some(high)) if flush => (PokerCategory::StraightFlush, vec![high]),
+        ([4, 1], _) => (PokerCategory::FourOfAKind, tie_breakers),
+        ([3, 2], _) => (PokerCategory::FullHouse, tie_breakers),
+        _ if flush => (PokerCategory::Flush, tie_breakers),
+        (_, Some(high)) => (PokerCategory::Straight, vec![high]),
+        ([3, 1, 1], _) => (PokerCategory::ThreeOfAKind, tie_breakers),
+        ([2, 2, 1], _) => (PokerCategory::TwoPair, tie_breakers),
+        ([2, 1, 1, 1], _) => (PokerCategory::OnePair, tie_breakers),
+        _ => (PokerCategory::HighCard, tie_breakers),
+    }
+}
+
+/// Category and tie breakers of a poker hand
+fn evaluate_hand(cards: &[Card], rules: &RuleSet) -> (PokerCategory, Vec<usize>) {
+    let ranks = cards.iter().map(|card| card.rank).collect::<Vec<usize>>();
+    let flush = cards.iter().map(|card| card.suit).all_equal();
+    evaluate(&ranks, flush, rules.order.len())
+}
+
+/// Total winnings of poker hands
+fn poker_winnings(bids: &[(Vec<Card>, u64)], rules: &RuleSet) -> u64 {
+    total_winnings(
+        bids.iter()
+            .map(|(cards, bet)| (evaluate_hand(cards, rules), *bet))
+            .collect(),
+    )
+}
+
+#[derive(Debug)]
+enum Bids {
+    CamelCards(Vec<(Vec<char>, u64)>),
+    Poker(Vec<(Vec<Card>, u64)>),
+}
+
+/// The parsed bid list, the hands are ranked by the rules of each part
+#[derive(Debug)]
+pub struct Game {
+    bids: Bids,
+}
+
+impl Game {
+    /// With `--poker` the hands are suited poker hands
+    pub fn parse(input: &[String], options: &Options) -> Result<Game, String> {
+        // The jokers of part 2 are ranked differently, but the cards are the same
+        let rules = RuleSet::standard();
+        let bids = if options.is_set("poker") {
+            Bids::Poker(parse_bid_list(input, |cards| {
+                parse_poker_hand(cards, &rules)
+            })?)
+        } else {
+            Bids::CamelCards(parse_hands(input, &rules)?)
+        };
+        Ok(Game { bids })
+    }
+
+    fn part_1_winnings(self: &Game) -> Result<u64, String> {
+        match &self.bids {
+            Bids::CamelCards(bids) => winnings(bids, &RuleSet::standard()),
+            Bids::Poker(bids) => Ok(poker_winnings(bids, &RuleSet::standard())),
+        }
+    }
+
+    /// Poker hands are ranked by the Camel Cards rules of part 1, the suits are ignored
+    fn part_2_winnings(self: &Game) -> Result<u64, String> {
+        match &self.bids {
+            Bids::CamelCards(bids) => winnings(bids, &RuleSet::jokers()),
+            Bids::Poker(bids) => {
+                let rules = RuleSet::standard();
+                let bids = bids
+                    .iter()
+                    .map(|(cards, bet)| {
+                        let cards = cards
+                            .iter()
+                            .map(|card| rules.order[card.rank])
+                            .collect::<Vec<char>>();
+                        (cards, *bet)
+                    })
+                    .collect::<Vec<(Vec<char>, u64)>>();
+                winnings(&bids, &rules)
+            }
+        }
+    }
+}
+
+impl Parts for Game {
+    fn part_1(&self) -> Result<String, String> {
+        self.part_1_winnings().map(|w| w.to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        self.part_2_winnings().map(|w| w.to_string())
+    }
+}
+
+/// With `--poker` the hands are suited poker hands. Part 1 ranks them by the rules of poker,
+/// part 2 by the Camel Cards rules of part 1 with the suits ignored, a J is a jack in both parts.
+pub fn solve(input: &[String], options: &Options) -> Result<(u64, u64), String> {
+    let game = Game::parse(input, options)?;
+    Ok((game.part_1_winnings()?, game.part_2_winnings()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    extern crate test;
+    use test::Bencher;
+
+    fn play_hands(input: &[String], rules: &RuleSet) -> Result<u64, String> {
+        winnings(&parse_hands(input, rules)?, rules)
+    }
+
+    #[test]
+    fn test_day7() {
+        let result_1 = 6440;
+        let result_2 = 5905;
+        let input = vec![
+            "32T3K 765".to_string(),
+            "T55J5 684".to_string(),
+            "KK677 28".to_string(),
+            "KTJJT 220".to_string(),
+            "QQQJA 483".to_string(),
+        ];
+        let (output_1, output_2) = solve(&input, &Options::default()).unwrap();
+        assert_eq!(result_1, output_1);
+        assert_eq!(result_2, output_2);
+        let game = Game::parse(&input, &Options::default()).unwrap();
+        assert_eq!(Ok(result_1.to_string()), game.part_1());
+        assert_eq!(Ok(result_2.to_string()), game.part_2());
+
+        let jokers = RuleSet::jokers();
+        assert_eq!(
+            HandType(vec![3, 2]),
+            HandType::classify(&['2', '2', 'J', '3', '3'], &jokers)
+        );
+        assert_eq!(
+            HandType(vec![5]),
+            HandType::classify(&['J', 'J', 'J', 'J', 'J'], &jokers)
+        );
+        // A variant where twos are wild as well
+        let wild_twos = RuleSet::new("2J3456789TQKA", "2J").unwrap();
+        assert_eq!(
+            HandType(vec![4, 1]),
+            HandType::classify(&['2', 'J', 'K', 'K', 'Q'], &wild_twos)
+        );
+    }
+
+    #[test]
+    fn test_day7_hand_sizes() {
+        // Three card hands with a four rank alphabet: pair of as, pair of bs, then the high cards
+        let rules = RuleSet::new("abcd", "").unwrap();
+        let input = vec![
+            "dca 1".to_string(),
+            "bab 10".to_string(),
+            "aab 100".to_string(),
+            "dcb 1000".to_string(),
+        ];
+        assert_eq!(Ok(1 + 2000 + 300 + 40), play_hands(&input, &rules));
+
+        // Seven cards: a four of a kind beats two triples, which beat a triple and a pair
+        let rules = RuleSet::standard();
+        let input = vec!["AAAA234 1".to_string(), "2223334 10".to_string()];
+        assert_eq!(Ok(2 + 10), play_hands(&input, &rules));
+        assert!(HandType(vec![3, 3, 1]) > HandType(vec![3, 2, 1, 1]));
+
+        // Long hands do not overflow the comparison key
+        let long_hand = "A".repeat(40) + " 5";
+        let longer_hand = "A".repeat(39) + "K 7";
+        assert_eq!(Ok(7 + 10), play_hands(&[long_hand, longer_hand], &rules));
+    }
+
+    #[test]
+    fn test_day7_validation() {
+        assert!(RuleSet::new("", "").is_err());
+        assert_eq!(
+            Err("rank 'K' appears twice".to_string()),
+            RuleSet::new("AKQK", "").map(|_| ())
+        );
+        assert_eq!(
+            Err("wild rank 'J' is not a rank".to_string()),
+            RuleSet::new("AKQ", "J").map(|_| ())
+        );
+        let input = vec![
+            "32T3K 765".to_string(),
+            "32T3 765".to_string(),
+            "32X3K 765".to_string(),
+            "32T3K many".to_string(),
+            "32T3K".to_string(),
+        ];
+        assert_eq!(
+            Err([
+                "line 2: 4 cards instead of 5",
+                "line 3: invalid card 'X'",
+                "line 4: invalid bet 'many'",
+                "line 5: expected cards and a bet",
+            ]
+            .join("\n")),
+            solve(&input, &Options::default())
+        );
+    }
+
+    #[test]
+    fn test_day7_poker() {
+        let rules = RuleSet::standard();
+        let key = |cards: &str| evaluate_hand(&parse_poker_hand(cards, &rules).unwrap(), &rules);
+        let ranking = [
+            "7h5d4c3s2h",
+            "AhQdTc8s6h",
+            "AhKdQc9s8h",
+            "2h2d5c4s3h",
+            "AhAd7c4s3h",
+            "AhAdKc4s3h",
+            "3h3d2c2sAh",
+            "4h4d2c2s3h",
+            "QhQdQcAs3h",
+            "Ah2d3c4s5h",
+            "6h2d3c4s5h",
+            "AhKdQcJsTh",
+            "9h7h5h4h2h",
+            "Kh7h5h4h2h",
+            "2h2d2c3s3h",
+            "2h2d2c2s3h",
+            "Ah2h3h4h5h",
+            "9h8h7h6h5h",
+            "AhKhQhJhTh",
+        ];
+        for (weaker, stronger) in ranking.iter().tuple_windows() {
+            assert!(key(weaker) < key(stronger), "{} < {}", weaker, stronger);
+        }
+        assert_eq!(key("AhKdQcJsTh"), key("AsKcQdJhTd"));
+        assert_eq!(PokerCategory::Straight, key("Ah2d3c4s5h").0);
+        assert_eq!(PokerCategory::StraightFlush, key("AhKhQhJhTh").0);
+
+        let input = vec![
+            "2h2d5c4s3h 10".to_string(),
+            "AhKdQcJsTh 1".to_string(),
+            "AhAdJcJs3h 100".to_string(),
+        ];
+        let (_, options) = Options::parse(&["--poker".to_string()]);
+        // Straight over two pair over pair of twos, by the Camel Cards rules the straight is only
+        // a high card
+        assert_eq!(
+            (10 + 200 + 3, 1 + 20 + 300),
+            solve(&input, &options).unwrap()
+        );
+
+        let input = vec![
+            "2h2d5c4s3 10".to_string(),
+            "2h2d5c4s3x 10".to_string(),
+            "2h2d5c4s1h 10".to_string(),
+            "2h2d5c2h3h 10".to_string(),
+        ];
+        assert_eq!(
+            Err([
+                "line 1: expected 5 suited cards",
+                "line 2: invalid suit 'x'",
+                "line 3: invalid rank '1'",
+                "line 4: 2h appears twice",
+            ]
+            .join("\n")),
+            solve(&input, &options)
+        );
+    }
+
+    #[bench]
+    fn bench_day7_part_1(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day7.txt");
+        b.iter(|| {
+            play_hands(&input, &RuleSet::standard()).unwrap();
+        });
+    }
+
+    #[bench]
+    fn bench_dat7_part_2(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day7.txt");
+        b.iter(|| {
+            play_hands(&input, &RuleSet::jokers()).unwrap();
+        });
+    }
+}
@@ -0,0 +1,273 @@
+use num::integer::lcm;
+use std::collections::BTreeMap;
+
+use crate::registry::Parts;
+use crate::repl::Explore;
+
+/// Parses the node lines like `AAA = (BBB, CCC)`, the first node line is line 3 of the input
+fn build_map(input: &[String]) -> Result<BTreeMap<String, (String, String)>, String> {
+    let mut map = BTreeMap::new();
+
+    for (index, line) in input.iter().enumerate() {
+        let node = line.split_once(" = ").and_then(|(source, targets)| {
+            let (left, right) = targets.split_once(", ")?;
+            Some((source, left.replace("(", ""), right.replace(")", "")))
+        });
+        let Some((source, left, right)) = node else {
+            return Err(format!("line {}: cannot parse node {}", index + 3, line));
+        };
+        map.insert(source.to_string(), (left, right));
+    }
+    Ok(map)
+}
+
+fn find_path(
+    start_position: &String,
+    instructions: &Vec<char>,
+    map: &BTreeMap<String, (String, String)>,
+    target_position: &String,
+) -> u64 {
+    let mut counter = 1;
+    let mut instruction_pointer = 0;
+    let mut current_position = start_position.clone();
+    loop {
+        let instruction: char = instructions[instruction_pointer];
+        let (left, right) = map.get(&current_position).unwrap();
+        match instruction {
+            'R' => {
+                current_position = right.clone();
+            }
+            'L' => {
+                current_position = left.clone();
+            }
+            _ => {
+                panic!("Unknown instruction {instruction}!")
+            }
+        }
+        if current_position.ends_with(target_position) {
+            break;
+        }
+        instruction_pointer += 1;
+        instruction_pointer %= instructions.len();
+        counter += 1;
+    }
+    counter
+}
+
+/// The parsed instructions and node table, which can be explored in the REPL
+pub struct Network {
+    instructions: Vec<char>,
+    map: BTreeMap<String, (String, String)>,
+}
+
+impl Network {
+    pub fn parse(input: &[String]) -> Result<Network, String> {
+        let instructions = input
+            .first()
+            .ok_or("The network is empty")?
+            .chars()
+            .collect::<Vec<char>>();
+        if instructions.is_empty() || instructions.iter().any(|i| *i != 'L' && *i != 'R') {
+            return Err(format!(
+                "line 1: instructions must be L and R, not {}",
+                input[0]
+            ));
+        }
+        let nodes = input.get(2..).ok_or("There are no nodes")?;
+        Ok(Network {
+            instructions,
+            map: build_map(nodes)?,
+        })
+    }
+
+    /// Steps from AAA to ZZZ
+    fn steps(self: &Network) -> u64 {
+        find_path(
+            &"AAA".to_string(),
+            &self.instructions,
+            &self.map,
+            &"ZZZ".to_string(),
+        )
+    }
+
+    /// Steps until all paths from the nodes ending with A are at a node ending with Z at the same time
+    fn ghost_steps(self: &Network) -> u64 {
+        let starting_positions = self
+            .map
+            .keys()
+            .filter(|k| k.ends_with("A"))
+            .map(|k| (*k).clone())
+            .collect::<Vec<String>>();
+
+        let counter = starting_positions
+            .iter()
+            .map(|p| find_path(p, &self.instructions, &self.map, &"Z".to_string()))
+            .collect::<Vec<u64>>();
+        let steps = counter
+            .iter()
+            .fold(self.instructions.len() as u64, |acc, x| lcm(acc, *x));
+        steps
+    }
+
+    /// Follows the instructions from start until a node ending with target is reached.
+    /// Unlike find_path this gives up once a state (node and instruction) repeats.
+    fn walk(self: &Network, start: &str, target: &str) -> Option<Vec<String>> {
+        let mut path = vec![start.to_string()];
+        let max_steps = self.map.len() * self.instructions.len();
+        for step in 0..max_steps {
+            let (left, right) = self.map.get(path.last().unwrap())?;
+            let next = match self.instructions[step % self.instructions.len()] {
+                'L' => left,
+                _ => right,
+            };
+            path.push(next.clone());
+            if next.ends_with(target) {
+                return Some(path);
+            }
+        }
+        None
+    }
+}
+
+impl Explore for Network {
+    fn help(self: &Network) -> Vec<&'static str> {
+        vec![
+            "node <name>                shows the left and right node",
+            "starts                     lists all nodes ending with A",
+            "path <start> [target]      follows the instructions to a node ending with target (default Z)",
+        ]
+    }
+
+    fn query(self: &Network, command: &str, arguments: &[&str]) -> Option<String> {
+        let output = match (command, arguments.first()) {
+            ("node", Some(name)) => match self.map.get(*name) {
+                Some((left, right)) => format!("{} = ({}, {})", name, left, right),
+                None => format!("There is no node {}", name),
+            },
+            ("starts", _) => self
+                .map
+                .keys()
+                .filter(|k| k.ends_with("A"))
+                .map(|k| k.as_str())
+                .collect::<Vec<&str>>()
+                .join(" "),
+            ("path", Some(start)) => {
+                let target = arguments.get(1).unwrap_or(&"Z");
+                match self.walk(start, target) {
+                    // Long paths are shortened to their start and end
+                    Some(path) if path.len() > 20 => format!(
+                        "{} steps: {} -> ... -> {}",
+                        path.len() - 1,
+                        path[..10].join(" -> "),
+                        path[path.len() - 10..].join(" -> ")
+                    ),
+                    Some(path) => format!("{} steps: {}", path.len() - 1, path.join(" -> ")),
+                    None => format!("{} never reaches a node ending with {}", start, target),
+                }
+            }
+            ("node", None) | ("path", None) => "Please specify the node".to_string(),
+            _ => return None,
+        };
+        Some(output)
+    }
+}
+
+impl Parts for Network {
+    fn part_1(&self) -> Result<String, String> {
+        Ok(self.steps().to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        Ok(self.ghost_steps().to_string())
+    }
+}
+
+pub fn solve(input: &[String]) -> Result<(u64, u64), String> {
+    let network = Network::parse(input)?;
+
+    let result_1 = network.steps();
+
+    let result_2 = network.ghost_steps();
+
+    Ok((result_1, result_2))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::my_io::read_input_to_vector;
+    extern crate test;
+    use test::Bencher;
+
+    #[test]
+    fn test_day8() {
+        let result_1 = 2;
+        let result_2 = 6;
+        let input_1 = vec![
+            "RL".to_string(),
+            "".to_string(),
+            "AAA = (BBB, CCC)".to_string(),
+            "BBB = (DDD, EEE)".to_string(),
+            "CCC = (ZZZ, GGG)".to_string(),
+            "DDD = (DDD, DDD)".to_string(),
+            "EEE = (EEE, EEE)".to_string(),
+            "GGG = (GGG, GGG)".to_string(),
+            "ZZZ = (ZZZ, ZZZ)".to_string(),
+        ];
+        let input_2 = vec![
+            "LR".to_string(),
+            "".to_string(),
+            "11A = (11B, XXX)".to_string(),
+            "11B = (XXX, 11Z)".to_string(),
+            "11Z = (11B, XXX)".to_string(),
+            "22A = (22B, XXX)".to_string(),
+            "22B = (22C, 22C)".to_string(),
+            "22C = (22Z, 22Z)".to_string(),
+            "22Z = (22B, 22B)".to_string(),
+            "XXX = (XXX, XXX)".to_string(),
+        ];
+        let output_1 = Network::parse(&input_1).unwrap().steps();
+        let output_2 = Network::parse(&input_2).unwrap().ghost_steps();
+        assert_eq!(result_1, output_1);
+        assert_eq!(result_2, output_2);
+
+        let network = Network::parse(&input_1).unwrap();
+        assert_eq!(
+            Some("2 steps: AAA -> CCC -> ZZZ".to_string()),
+            network.query("path", &["AAA", "ZZZ"])
+        );
+        assert_eq!(
+            Some("DDD never reaches a node ending with Z".to_string()),
+            network.query("path", &["DDD"])
+        );
+
+        assert_eq!(
+            Err("The network is empty".to_string()),
+            Network::parse(&[]).map(|_| ())
+        );
+        assert_eq!(
+            Err("There are no nodes".to_string()),
+            Network::parse(&["RL".to_string()]).map(|_| ())
+        );
+        assert_eq!(
+            Err("line 3: cannot parse node AAA".to_string()),
+            Network::parse(&["RL".to_string(), "".to_string(), "AAA".to_string()]).map(|_| ())
+        );
+    }
+
+    #[bench]
+    fn bench_day8_part_1(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day8.txt");
+        b.iter(|| {
+            Network::parse(&input).unwrap().steps();
+        });
+    }
+
+    #[bench]
+    fn bench_day8_part_2(b: &mut Bencher) {
+        let input = read_input_to_vector("data/2023/day8.txt");
+        b.iter(|| {
+            Network::parse(&input).unwrap().ghost_steps();
+        });
+    }
+}
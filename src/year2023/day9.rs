@@ -1,5 +1,7 @@
 use std::iter::zip;
 
+use crate::registry::Parts;
+
 fn predict_next(input: &Vec<i64>) -> i64 {
     if input.len() < 2 {
         return input[0];
@@ -30,18 +32,47 @@ fn predict_previous(input: &Vec<i64>) -> i64 {
     }
 }
 
-pub fn solve(input: &Vec<String>) -> (i64, i64) {
-    let mut sum_next = 0;
-    let mut sum_previous = 0;
-    for line in input.iter() {
-        let numbers = line
-            .split_whitespace()
-            .map(|x| x.parse::<i64>().unwrap())
-            .collect::<Vec<i64>>();
-        sum_next += predict_next(&numbers);
-        sum_previous += predict_previous(&numbers);
+/// The parsed histories of the report
+pub struct Histories {
+    histories: Vec<Vec<i64>>,
+}
+
+impl Histories {
+    pub fn parse(input: &[String]) -> Histories {
+        Histories {
+            histories: input
+                .iter()
+                .map(|line| {
+                    line.split_whitespace()
+                        .map(|x| x.parse::<i64>().unwrap())
+                        .collect::<Vec<i64>>()
+                })
+                .collect(),
+        }
+    }
+
+    fn sum_next(self: &Histories) -> i64 {
+        self.histories.iter().map(predict_next).sum()
+    }
+
+    fn sum_previous(self: &Histories) -> i64 {
+        self.histories.iter().map(predict_previous).sum()
     }
-    (sum_next, sum_previous)
+}
+
+impl Parts for Histories {
+    fn part_1(&self) -> Result<String, String> {
+        Ok(self.sum_next().to_string())
+    }
+
+    fn part_2(&self) -> Result<String, String> {
+        Ok(self.sum_previous().to_string())
+    }
+}
+
+pub fn solve(input: &[String]) -> (i64, i64) {
+    let histories = Histories::parse(input);
+    (histories.sum_next(), histories.sum_previous())
 }
 
 #[cfg(test)]
@@ -67,7 +98,7 @@ mod tests {
 
     #[bench]
     fn bench_day9_both(b: &mut Bencher) {
-        let input = read_input_to_vector("data/day9.txt");
+        let input = read_input_to_vector("data/2023/day9.txt");
         b.iter(|| {
             solve(&input);
         });
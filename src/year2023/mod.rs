@@ -0,0 +1,143 @@
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+use crate::options::Options;
+use crate::registry::{to_answers, with_report, Parts, Solution};
+use crate::repl::Explore;
+
+/// Solves a day of 2023 and returns both answers as strings, or None if there is no solution for this day yet
+pub fn solve_day(
+    day: u32,
+    input: &Vec<String>,
+    options: &Options,
+) -> Option<Result<Solution, String>> {
+    let answers = match day {
+        1 => day1::solve(input, options).map(with_report),
+        2 => day2::solve(input, options).map(with_report),
+        3 => day3::solve(input, options).map(to_answers),
+        4 => day4::solve(input, options).map(with_report),
+        5 => day5::solve(input).map(to_answers),
+        6 => day6::solve(input, options).map(with_report),
+        7 => day7::solve(input, options).map(to_answers),
+        8 => day8::solve(input).map(to_answers),
+        9 => Ok(to_answers(day9::solve(input))),
+        10 => Ok(to_answers(day10::solve(input))),
+        11 => Ok(to_answers(day11::solve(input))),
+        12 => Ok(to_answers(day12::solve(input))),
+        13 => day13::solve(input).map(to_answers),
+        _ => return None,
+    };
+    Some(answers)
+}
+
+fn boxed<'a, T: Parts + 'a>(parts: T) -> Box<dyn Parts + 'a> {
+    Box::new(parts)
+}
+
+/// Parses a day of 2023 for solving its parts separately. Day 10 computes both parts together,
+/// the inner tiles need the loop of part 1, and is only profiled as a whole.
+pub fn parse_day<'a>(
+    day: u32,
+    input: &'a Vec<String>,
+    options: &Options,
+) -> Option<Result<Box<dyn Parts + 'a>, String>> {
+    let parts = match day {
+        1 => day1::Document::parse(input, options).map(boxed),
+        2 => Ok(boxed(day2::Record::parse(input, options))),
+        3 => day3::Engine::parse(input, options).map(boxed),
+        4 => day4::Scratchcards::parse(input, options).map(boxed),
+        5 => day5::Almanac::parse(input)
+            .and_then(|almanac| almanac.seed_locations())
+            .map(boxed),
+        6 => day6::RaceSheet::parse(input, options).map(boxed),
+        7 => day7::Game::parse(input, options).map(boxed),
+        8 => day8::Network::parse(input).map(boxed),
+        9 => Ok(boxed(day9::Histories::parse(input))),
+        11 => Ok(boxed(day11::Image::parse(input))),
+        12 => Ok(boxed(day12::Records::parse(input))),
+        13 => day13::Valley::parse(input).map(boxed),
+        _ => return None,
+    };
+    Some(parts)
+}
+
+fn boxed_explore<'a, T: Explore + 'a>(explore: T) -> Box<dyn Explore + 'a> {
+    Box::new(explore)
+}
+
+/// The parsed puzzle state of a day for the REPL, None if the day has nothing to explore
+/// The options select the rules of days that can be configured
+pub fn explore(
+    day: u32,
+    input: &Vec<String>,
+    options: &Options,
+) -> Option<Result<Box<dyn Explore>, String>> {
+    let explore = match day {
+        3 => day3::Engine::parse(input, options).map(boxed_explore),
+        5 => day5::Almanac::parse(input).map(boxed_explore),
+        8 => day8::Network::parse(input).map(boxed_explore),
+        13 => day13::Valley::parse(input).map(boxed_explore),
+        _ => return None,
+    };
+    Some(explore)
+}
+
+pub fn describe(day: u32, result_1: &String, result_2: &String) -> String {
+    match day {
+        1 => format!(
+            "Sum of all calibrations: part 1: {} - part 2: {}",
+            result_1, result_2
+        ),
+        2 => format!(
+            "Sum of all IDs: {} - and the sum of power: {}",
+            result_1, result_2
+        ),
+        3 => format!(
+            "Sum of all part numbers: {} - sum of all gear ratios: {}",
+            result_1, result_2
+        ),
+        4 => format!("total points: {} - total cards: {}", result_1, result_2),
+        5 => format!(
+            "Lowest location number: {} - lowest number for ranges: {}",
+            result_1, result_2
+        ),
+        6 => format!(
+            "Win product: {} - ways to win the long race: {}",
+            result_1, result_2
+        ),
+        7 => format!(
+            "Total winnings: part 1: {} - part 2: {}",
+            result_1, result_2
+        ),
+        8 => format!(
+            "Steps to reach ZZZ: {} - Steps to reach **Z: {}",
+            result_1, result_2
+        ),
+        9 => format!(
+            "Sum of extrapolated next values {} - previous values {}",
+            result_1, result_2
+        ),
+        10 => format!("Maximum distance {} - inner tiles {}", result_1, result_2),
+        11 => format!(
+            "Distance sum {} - larger distance sum {}",
+            result_1, result_2
+        ),
+        12 => format!("Distance sum {} - inner tiles {}", result_1, result_2),
+        13 => format!(
+            "Reflection sum {} - smudge reflection sum {}",
+            result_1, result_2
+        ),
+        _ => format!("part 1: {} - part 2: {}", result_1, result_2),
+    }
+}